@@ -1,5 +1,5 @@
 use clap::{Arg, Command};
-use rand::{Rng, RngCore, SeedableRng};
+use rand::{Rng, SeedableRng};
 use std::process;
 use std::str::FromStr;
 use tinymt::{TinyMT32, TinyMT64};
@@ -0,0 +1,13 @@
+/// Compile the original TinyMT 1.1.1 C implementation vendored in `vendor/tinymt`.
+fn main() {
+  cc::Build::new()
+    .include("vendor/tinymt")
+    .file("vendor/tinymt/tinymt32.c")
+    .file("vendor/tinymt/tinymt64.c")
+    .file("src/shim.c")
+    .flag_if_supported("-std=c99")
+    .warnings(false)
+    .compile("tinymt_reference");
+  println!("cargo:rerun-if-changed=vendor/tinymt");
+  println!("cargo:rerun-if-changed=src/shim.c");
+}
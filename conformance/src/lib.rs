@@ -0,0 +1,174 @@
+//! Safe bindings to the original TinyMT 1.1.1 C implementation vendored in `vendor/tinymt`,
+//! named after the functions of `tinymt::tinymt32` and `tinymt::tinymt64` so that both
+//! implementations can be compared call by call.
+//!
+//! `TinyMT32` and `TinyMT64` have the same memory layout as `tinymt32_t` and `tinymt64_t`, so
+//! they are passed to the C functions as they are.
+use std::os::raw::c_int;
+
+use tinymt::{TinyMT32, TinyMT64};
+
+extern "C" {
+  fn ref_tinymt32_init(random: *mut TinyMT32, seed: u32);
+  fn ref_tinymt32_init_by_array(random: *mut TinyMT32, init_key: *const u32, key_length: c_int);
+  fn ref_tinymt32_get_mexp(random: *mut TinyMT32) -> i32;
+  fn ref_tinymt32_next_state(random: *mut TinyMT32);
+  fn ref_tinymt32_temper(random: *mut TinyMT32) -> u32;
+  fn ref_tinymt32_temper_conv(random: *mut TinyMT32) -> f32;
+  fn ref_tinymt32_temper_conv_open(random: *mut TinyMT32) -> f32;
+  fn ref_tinymt32_generate_uint32(random: *mut TinyMT32) -> u32;
+  fn ref_tinymt32_generate_float(random: *mut TinyMT32) -> f32;
+  fn ref_tinymt32_generate_float12(random: *mut TinyMT32) -> f32;
+  fn ref_tinymt32_generate_float01(random: *mut TinyMT32) -> f32;
+  fn ref_tinymt32_generate_floatOC(random: *mut TinyMT32) -> f32;
+  fn ref_tinymt32_generate_floatOO(random: *mut TinyMT32) -> f32;
+  fn ref_tinymt32_generate_32double(random: *mut TinyMT32) -> f64;
+  fn ref_tinymt64_init(random: *mut TinyMT64, seed: u64);
+  fn ref_tinymt64_init_by_array(random: *mut TinyMT64, init_key: *const u64, key_length: c_int);
+  fn ref_tinymt64_get_mexp(random: *mut TinyMT64) -> i32;
+  fn ref_tinymt64_next_state(random: *mut TinyMT64);
+  fn ref_tinymt64_temper(random: *mut TinyMT64) -> u64;
+  fn ref_tinymt64_temper_conv(random: *mut TinyMT64) -> f64;
+  fn ref_tinymt64_temper_conv_open(random: *mut TinyMT64) -> f64;
+  fn ref_tinymt64_generate_uint64(random: *mut TinyMT64) -> u64;
+  fn ref_tinymt64_generate_double(random: *mut TinyMT64) -> f64;
+  fn ref_tinymt64_generate_double01(random: *mut TinyMT64) -> f64;
+  fn ref_tinymt64_generate_double12(random: *mut TinyMT64) -> f64;
+  fn ref_tinymt64_generate_doubleOC(random: *mut TinyMT64) -> f64;
+  fn ref_tinymt64_generate_doubleOO(random: *mut TinyMT64) -> f64;
+}
+
+/// `tinymt32_init()` of the original implementation.
+pub fn tinymt32_init(random: &mut TinyMT32, seed: u32) {
+  unsafe { ref_tinymt32_init(random, seed) }
+}
+
+/// `tinymt32_init_by_array()` of the original implementation.
+pub fn tinymt32_init_by_array(random: &mut TinyMT32, init_key: &[u32]) {
+  unsafe { ref_tinymt32_init_by_array(random, init_key.as_ptr(), init_key.len() as c_int) }
+}
+
+/// `tinymt32_get_mexp()` of the original implementation.
+pub fn tinymt32_get_mexp(random: &mut TinyMT32) -> usize {
+  unsafe { ref_tinymt32_get_mexp(random) as usize }
+}
+
+/// `tinymt32_next_state()` of the original implementation.
+pub fn tinymt32_next_state(random: &mut TinyMT32) {
+  unsafe { ref_tinymt32_next_state(random) }
+}
+
+/// `tinymt32_temper()` of the original implementation.
+pub fn tinymt32_temper(random: &mut TinyMT32) -> u32 {
+  unsafe { ref_tinymt32_temper(random) }
+}
+
+/// `tinymt32_temper_conv()` of the original implementation.
+pub fn tinymt32_temper_conv(random: &mut TinyMT32) -> f32 {
+  unsafe { ref_tinymt32_temper_conv(random) }
+}
+
+/// `tinymt32_temper_conv_open()` of the original implementation.
+pub fn tinymt32_temper_conv_open(random: &mut TinyMT32) -> f32 {
+  unsafe { ref_tinymt32_temper_conv_open(random) }
+}
+
+/// `tinymt32_generate_uint32()` of the original implementation.
+pub fn tinymt32_generate_uint32(random: &mut TinyMT32) -> u32 {
+  unsafe { ref_tinymt32_generate_uint32(random) }
+}
+
+/// `tinymt32_generate_float()` of the original implementation.
+pub fn tinymt32_generate_float(random: &mut TinyMT32) -> f32 {
+  unsafe { ref_tinymt32_generate_float(random) }
+}
+
+/// `tinymt32_generate_float12()` of the original implementation.
+pub fn tinymt32_generate_float12(random: &mut TinyMT32) -> f32 {
+  unsafe { ref_tinymt32_generate_float12(random) }
+}
+
+/// `tinymt32_generate_float01()` of the original implementation.
+pub fn tinymt32_generate_float01(random: &mut TinyMT32) -> f32 {
+  unsafe { ref_tinymt32_generate_float01(random) }
+}
+
+/// `tinymt32_generate_floatOC()` of the original implementation.
+pub fn tinymt32_generate_float_oc(random: &mut TinyMT32) -> f32 {
+  unsafe { ref_tinymt32_generate_floatOC(random) }
+}
+
+/// `tinymt32_generate_floatOO()` of the original implementation.
+pub fn tinymt32_generate_float_oo(random: &mut TinyMT32) -> f32 {
+  unsafe { ref_tinymt32_generate_floatOO(random) }
+}
+
+/// `tinymt32_generate_32double()` of the original implementation.
+pub fn tinymt32_generate_32double(random: &mut TinyMT32) -> f64 {
+  unsafe { ref_tinymt32_generate_32double(random) }
+}
+
+/// `tinymt64_init()` of the original implementation.
+pub fn tinymt64_init(random: &mut TinyMT64, seed: u64) {
+  unsafe { ref_tinymt64_init(random, seed) }
+}
+
+/// `tinymt64_init_by_array()` of the original implementation.
+pub fn tinymt64_init_by_array(random: &mut TinyMT64, init_key: &[u64]) {
+  unsafe { ref_tinymt64_init_by_array(random, init_key.as_ptr(), init_key.len() as c_int) }
+}
+
+/// `tinymt64_get_mexp()` of the original implementation.
+pub fn tinymt64_get_mexp(random: &mut TinyMT64) -> usize {
+  unsafe { ref_tinymt64_get_mexp(random) as usize }
+}
+
+/// `tinymt64_next_state()` of the original implementation.
+pub fn tinymt64_next_state(random: &mut TinyMT64) {
+  unsafe { ref_tinymt64_next_state(random) }
+}
+
+/// `tinymt64_temper()` of the original implementation.
+pub fn tinymt64_temper(random: &mut TinyMT64) -> u64 {
+  unsafe { ref_tinymt64_temper(random) }
+}
+
+/// `tinymt64_temper_conv()` of the original implementation.
+pub fn tinymt64_temper_conv(random: &mut TinyMT64) -> f64 {
+  unsafe { ref_tinymt64_temper_conv(random) }
+}
+
+/// `tinymt64_temper_conv_open()` of the original implementation.
+pub fn tinymt64_temper_conv_open(random: &mut TinyMT64) -> f64 {
+  unsafe { ref_tinymt64_temper_conv_open(random) }
+}
+
+/// `tinymt64_generate_uint64()` of the original implementation.
+pub fn tinymt64_generate_uint64(random: &mut TinyMT64) -> u64 {
+  unsafe { ref_tinymt64_generate_uint64(random) }
+}
+
+/// `tinymt64_generate_double()` of the original implementation.
+pub fn tinymt64_generate_double(random: &mut TinyMT64) -> f64 {
+  unsafe { ref_tinymt64_generate_double(random) }
+}
+
+/// `tinymt64_generate_double01()` of the original implementation.
+pub fn tinymt64_generate_double01(random: &mut TinyMT64) -> f64 {
+  unsafe { ref_tinymt64_generate_double01(random) }
+}
+
+/// `tinymt64_generate_double12()` of the original implementation.
+pub fn tinymt64_generate_double12(random: &mut TinyMT64) -> f64 {
+  unsafe { ref_tinymt64_generate_double12(random) }
+}
+
+/// `tinymt64_generate_doubleOC()` of the original implementation.
+pub fn tinymt64_generate_double_oc(random: &mut TinyMT64) -> f64 {
+  unsafe { ref_tinymt64_generate_doubleOC(random) }
+}
+
+/// `tinymt64_generate_doubleOO()` of the original implementation.
+pub fn tinymt64_generate_double_oo(random: &mut TinyMT64) -> f64 {
+  unsafe { ref_tinymt64_generate_doubleOO(random) }
+}
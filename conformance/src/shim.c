@@ -0,0 +1,114 @@
+/*
+ * Non-inline entry points of the original TinyMT functions, so that the functions declared as
+ * `inline static` in tinymt32.h and tinymt64.h can be called from Rust.
+ */
+#include "tinymt32.h"
+#include "tinymt64.h"
+
+void ref_tinymt32_init(tinymt32_t * random, uint32_t seed) {
+    tinymt32_init(random, seed);
+}
+
+void ref_tinymt32_init_by_array(tinymt32_t * random, uint32_t init_key[], int key_length) {
+    tinymt32_init_by_array(random, init_key, key_length);
+}
+
+int ref_tinymt32_get_mexp(tinymt32_t * random) {
+    return tinymt32_get_mexp(random);
+}
+
+void ref_tinymt32_next_state(tinymt32_t * random) {
+    tinymt32_next_state(random);
+}
+
+uint32_t ref_tinymt32_temper(tinymt32_t * random) {
+    return tinymt32_temper(random);
+}
+
+float ref_tinymt32_temper_conv(tinymt32_t * random) {
+    return tinymt32_temper_conv(random);
+}
+
+float ref_tinymt32_temper_conv_open(tinymt32_t * random) {
+    return tinymt32_temper_conv_open(random);
+}
+
+uint32_t ref_tinymt32_generate_uint32(tinymt32_t * random) {
+    return tinymt32_generate_uint32(random);
+}
+
+float ref_tinymt32_generate_float(tinymt32_t * random) {
+    return tinymt32_generate_float(random);
+}
+
+float ref_tinymt32_generate_float12(tinymt32_t * random) {
+    return tinymt32_generate_float12(random);
+}
+
+float ref_tinymt32_generate_float01(tinymt32_t * random) {
+    return tinymt32_generate_float01(random);
+}
+
+float ref_tinymt32_generate_floatOC(tinymt32_t * random) {
+    return tinymt32_generate_floatOC(random);
+}
+
+float ref_tinymt32_generate_floatOO(tinymt32_t * random) {
+    return tinymt32_generate_floatOO(random);
+}
+
+double ref_tinymt32_generate_32double(tinymt32_t * random) {
+    return tinymt32_generate_32double(random);
+}
+
+void ref_tinymt64_init(tinymt64_t * random, uint64_t seed) {
+    tinymt64_init(random, seed);
+}
+
+void ref_tinymt64_init_by_array(tinymt64_t * random, const uint64_t init_key[], int key_length) {
+    tinymt64_init_by_array(random, init_key, key_length);
+}
+
+int ref_tinymt64_get_mexp(tinymt64_t * random) {
+    return tinymt64_get_mexp(random);
+}
+
+void ref_tinymt64_next_state(tinymt64_t * random) {
+    tinymt64_next_state(random);
+}
+
+uint64_t ref_tinymt64_temper(tinymt64_t * random) {
+    return tinymt64_temper(random);
+}
+
+double ref_tinymt64_temper_conv(tinymt64_t * random) {
+    return tinymt64_temper_conv(random);
+}
+
+double ref_tinymt64_temper_conv_open(tinymt64_t * random) {
+    return tinymt64_temper_conv_open(random);
+}
+
+uint64_t ref_tinymt64_generate_uint64(tinymt64_t * random) {
+    return tinymt64_generate_uint64(random);
+}
+
+double ref_tinymt64_generate_double(tinymt64_t * random) {
+    return tinymt64_generate_double(random);
+}
+
+double ref_tinymt64_generate_double01(tinymt64_t * random) {
+    return tinymt64_generate_double01(random);
+}
+
+double ref_tinymt64_generate_double12(tinymt64_t * random) {
+    return tinymt64_generate_double12(random);
+}
+
+double ref_tinymt64_generate_doubleOC(tinymt64_t * random) {
+    return tinymt64_generate_doubleOC(random);
+}
+
+double ref_tinymt64_generate_doubleOO(tinymt64_t * random) {
+    return tinymt64_generate_doubleOO(random);
+}
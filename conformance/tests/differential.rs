@@ -0,0 +1,202 @@
+//! Differential tests that compare every public function of `tinymt::tinymt32` and
+//! `tinymt::tinymt64` with the original C implementation across random seeds, keys and
+//! parameter sets. The number of random cases is 10,000 by default and can be changed by the
+//! environment variable `TINYMT_DIFF_ITERATIONS`, e.g.
+//!
+//! ```shell
+//! TINYMT_DIFF_ITERATIONS=10000000 cargo test --release -p tinymt-conformance
+//! ```
+use std::env;
+
+use tinymt::tinymt32 as rust32;
+use tinymt::tinymt64 as rust64;
+use tinymt::{TinyMT32, TinyMT64};
+use tinymt_conformance as c;
+
+/// The number of outputs compared for each generate function.
+const CALLS: usize = 8;
+
+/// The number of random cases.
+fn iterations() -> usize {
+  env::var("TINYMT_DIFF_ITERATIONS").ok().and_then(|n| n.parse().ok()).unwrap_or(10_000)
+}
+
+/// SplitMix64, used to produce the test cases independently of the implementation under test.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+  fn next(&mut self) -> u64 {
+    self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = self.0;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+  }
+
+  /// Random key length; mostly short keys, sometimes up to 1000 elements.
+  fn key_length(&mut self) -> usize {
+    match self.next() % 4 {
+      0 => (self.next() % 1001) as usize,
+      _ => (self.next() % 17) as usize,
+    }
+  }
+}
+
+fn random_tinymt32(rng: &mut SplitMix64) -> TinyMT32 {
+  TinyMT32::new([0, 0, 0, 0], rng.next() as u32, rng.next() as u32, rng.next() as u32)
+}
+
+fn random_tinymt64(rng: &mut SplitMix64) -> TinyMT64 {
+  TinyMT64::new([0, 0], rng.next() as u32, rng.next() as u32, rng.next())
+}
+
+/// The original implementation must reproduce check32.c and check64.c, otherwise the vendored
+/// sources themselves are broken.
+#[test]
+fn test_reference_implementation() {
+  let mut tinymt = TinyMT32::new([0, 0, 0, 0], 0x8f7011ee, 0xfc78ff1f, 0x3793fdff);
+  c::tinymt32_init(&mut tinymt, 1);
+  assert_eq!(127, c::tinymt32_get_mexp(&mut tinymt));
+  for expected in [2545341989, 981918433, 3715302833, 2387538352, 3591001365] {
+    assert_eq!(expected, c::tinymt32_generate_uint32(&mut tinymt));
+  }
+  let mut tinymt = TinyMT64::new([0, 0], 0xfa051f40, 0xffd0fff4, 0x58d02ffeffbfffbc);
+  c::tinymt64_init(&mut tinymt, 1);
+  assert_eq!(127, c::tinymt64_get_mexp(&mut tinymt));
+  for expected in [15503804787016557143, 17280942441431881838, 2177846447079362065] {
+    assert_eq!(expected, c::tinymt64_generate_uint64(&mut tinymt));
+  }
+}
+
+#[test]
+fn test_tinymt32_init() {
+  let mut rng = SplitMix64(32);
+  let mut cases = vec![(TinyMT32::new([0, 0, 0, 0], 0, 0, 0), 0)];
+  cases.extend((0..iterations()).map(|_| (random_tinymt32(&mut rng), rng.next() as u32)));
+  for (tinymt, seed) in cases {
+    let (mut actual, mut expected) = (tinymt, tinymt);
+    rust32::tinymt32_init(&mut actual, seed);
+    c::tinymt32_init(&mut expected, seed);
+    assert_eq!(expected, actual, "tinymt32_init({:?}, {})", tinymt, seed);
+    assert_generate32(&mut actual, &mut expected);
+  }
+}
+
+#[test]
+fn test_tinymt32_init_by_array() {
+  let mut rng = SplitMix64(3232);
+  let mut cases = vec![(TinyMT32::new([0, 0, 0, 0], 0, 0, 0), vec![])];
+  cases.extend((0..iterations()).map(|_| {
+    let key = (0..rng.key_length()).map(|_| rng.next() as u32).collect::<Vec<_>>();
+    (random_tinymt32(&mut rng), key)
+  }));
+  for (tinymt, key) in cases {
+    let (mut actual, mut expected) = (tinymt, tinymt);
+    rust32::tinymt32_init_by_array(&mut actual, &key);
+    c::tinymt32_init_by_array(&mut expected, &key);
+    assert_eq!(expected, actual, "tinymt32_init_by_array({:?}, {:?})", tinymt, key);
+    assert_generate32(&mut actual, &mut expected);
+  }
+}
+
+#[test]
+fn test_tinymt64_init() {
+  let mut rng = SplitMix64(64);
+  let mut cases = vec![(TinyMT64::new([0, 0], 0, 0, 0), 0)];
+  cases.extend((0..iterations()).map(|_| (random_tinymt64(&mut rng), rng.next())));
+  for (tinymt, seed) in cases {
+    let (mut actual, mut expected) = (tinymt, tinymt);
+    rust64::tinymt64_init(&mut actual, seed);
+    c::tinymt64_init(&mut expected, seed);
+    assert_eq!(expected, actual, "tinymt64_init({:?}, {})", tinymt, seed);
+    assert_generate64(&mut actual, &mut expected);
+  }
+}
+
+#[test]
+fn test_tinymt64_init_by_array() {
+  let mut rng = SplitMix64(6464);
+  let mut cases = vec![(TinyMT64::new([0, 0], 0, 0, 0), vec![])];
+  cases.extend((0..iterations()).map(|_| {
+    let key = (0..rng.key_length()).map(|_| rng.next()).collect::<Vec<_>>();
+    (random_tinymt64(&mut rng), key)
+  }));
+  for (tinymt, key) in cases {
+    let (mut actual, mut expected) = (tinymt, tinymt);
+    rust64::tinymt64_init_by_array(&mut actual, &key);
+    c::tinymt64_init_by_array(&mut expected, &key);
+    assert_eq!(expected, actual, "tinymt64_init_by_array({:?}, {:?})", tinymt, key);
+    assert_generate64(&mut actual, &mut expected);
+  }
+}
+
+/// Compare each generate function of both implementations on the same initial state.
+fn assert_generate32(actual: &mut TinyMT32, expected: &mut TinyMT32) {
+  macro_rules! compare {
+    ($rust:path, $c:path) => {
+      for _ in 0..CALLS {
+        let (a, e) = ($rust(actual), $c(expected));
+        assert_eq!(e.to_bits(), a.to_bits(), "{}: {} != {}", stringify!($rust), a, e);
+        assert_eq!(expected, actual, "{}", stringify!($rust));
+      }
+    };
+  }
+  for _ in 0..CALLS {
+    rust32::tinymt32_next_state(actual);
+    c::tinymt32_next_state(expected);
+    assert_eq!(expected, actual);
+    assert_eq!(c::tinymt32_temper(expected), rust32::tinymt32_temper(actual));
+    assert_eq!(
+      c::tinymt32_temper_conv(expected).to_bits(),
+      rust32::tinymt32_temper_conv(actual).to_bits()
+    );
+    assert_eq!(
+      c::tinymt32_temper_conv_open(expected).to_bits(),
+      rust32::tinymt32_temper_conv_open(actual).to_bits()
+    );
+  }
+  for _ in 0..CALLS {
+    assert_eq!(c::tinymt32_generate_uint32(expected), rust32::tinymt32_generate_uint32(actual));
+  }
+  compare!(rust32::tinymt32_generate_float, c::tinymt32_generate_float);
+  compare!(rust32::tinymt32_generate_float12, c::tinymt32_generate_float12);
+  compare!(rust32::tinymt32_generate_float01, c::tinymt32_generate_float01);
+  compare!(rust32::tinymt32_generate_float_oc, c::tinymt32_generate_float_oc);
+  compare!(rust32::tinymt32_generate_float_oo, c::tinymt32_generate_float_oo);
+  compare!(rust32::tinymt32_generate_32double, c::tinymt32_generate_32double);
+}
+
+/// Compare each generate function of both implementations on the same initial state.
+fn assert_generate64(actual: &mut TinyMT64, expected: &mut TinyMT64) {
+  macro_rules! compare {
+    ($rust:path, $c:path) => {
+      for _ in 0..CALLS {
+        let (a, e) = ($rust(actual), $c(expected));
+        assert_eq!(e.to_bits(), a.to_bits(), "{}: {} != {}", stringify!($rust), a, e);
+        assert_eq!(expected, actual, "{}", stringify!($rust));
+      }
+    };
+  }
+  for _ in 0..CALLS {
+    rust64::tinymt64_next_state(actual);
+    c::tinymt64_next_state(expected);
+    assert_eq!(expected, actual);
+    assert_eq!(c::tinymt64_temper(expected), rust64::tinymt64_temper(actual));
+    assert_eq!(
+      c::tinymt64_temper_conv(expected).to_bits(),
+      rust64::tinymt64_temper_conv(actual).to_bits()
+    );
+    assert_eq!(
+      c::tinymt64_temper_conv_open(expected).to_bits(),
+      rust64::tinymt64_temper_conv_open(actual).to_bits()
+    );
+  }
+  for _ in 0..CALLS {
+    assert_eq!(c::tinymt64_generate_uint64(expected), rust64::tinymt64_generate_uint64(actual));
+  }
+  compare!(rust64::tinymt64_generate_double, c::tinymt64_generate_double);
+  compare!(rust64::tinymt64_generate_double01, c::tinymt64_generate_double01);
+  compare!(rust64::tinymt64_generate_double12, c::tinymt64_generate_double12);
+  compare!(rust64::tinymt64_generate_double_oc, c::tinymt64_generate_double_oc);
+  compare!(rust64::tinymt64_generate_double_oo, c::tinymt64_generate_double_oo);
+}
@@ -0,0 +1,205 @@
+/* TinyMT 64/32 - C ABI of the Rust implementation, compatible with tinymt32.h and tinymt64.h */
+
+#ifndef TINYMT_H
+#define TINYMT_H
+
+/* This file is generated by cbindgen from the tinymt-ffi crate. Don't modify it manually. */
+
+#include <stdint.h>
+
+#define TINYMT32_MUL (1.0f / 16777216.0f)
+
+#define TINYMT32_MEXP 127
+
+#define TINYMT32_SH0 1
+
+#define TINYMT32_SH1 10
+
+#define TINYMT32_SH8 8
+
+#define TINYMT32_MASK 2147483647
+
+#define TINYMT64_MEXP 127
+
+#define TINYMT64_SH0 12
+
+#define TINYMT64_SH1 11
+
+#define TINYMT64_SH8 8
+
+#define TINYMT64_MASK 9223372036854775807
+
+#define TINYMT64_MUL (1.0 / 9007199254740992.0)
+
+/*
+ tinymt32 internal state vector and parameters
+ */
+typedef struct TINYMT32_T {
+  uint32_t status[4];
+  uint32_t mat1;
+  uint32_t mat2;
+  uint32_t tmat;
+} TINYMT32_T;
+
+typedef struct TINYMT32_T tinymt32_t;
+
+/*
+ tinymt64 internal state vector and parameters
+ */
+typedef struct TINYMT64_T {
+  uint64_t status[2];
+  uint32_t mat1;
+  uint32_t mat2;
+  uint64_t tmat;
+} TINYMT64_T;
+
+typedef struct TINYMT64_T tinymt64_t;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/*
+ This function initializes the internal state array with a 32-bit unsigned integer seed.
+ */
+void tinymt32_init(tinymt32_t *random, uint32_t seed);
+
+/*
+ This function initializes the internal state array, with an array of 32-bit unsigned integers used as seeds.
+ */
+void tinymt32_init_by_array(tinymt32_t *random,
+                            const uint32_t *init_key,
+                            int key_length);
+
+/*
+ This function always returns 127.
+ */
+int tinymt32_get_mexp(tinymt32_t *random);
+
+/*
+ This function changes internal state of tinymt32.
+ */
+void tinymt32_next_state(tinymt32_t *random);
+
+/*
+ This function outputs 32-bit unsigned integer from internal state.
+ */
+uint32_t tinymt32_temper(tinymt32_t *random);
+
+/*
+ This function outputs floating point number r (1.0 <= r < 2.0) from internal state.
+ */
+float tinymt32_temper_conv(tinymt32_t *random);
+
+/*
+ This function outputs floating point number r (1.0 < r < 2.0) from internal state.
+ */
+float tinymt32_temper_conv_open(tinymt32_t *random);
+
+/*
+ This function outputs 32-bit unsigned integer r (0 <= r < 2^32).
+ */
+uint32_t tinymt32_generate_uint32(tinymt32_t *random);
+
+/*
+ This function outputs floating point number r (0.0 <= r < 1.0).
+ */
+float tinymt32_generate_float(tinymt32_t *random);
+
+/*
+ This function outputs floating point number r (1.0 <= r < 2.0).
+ */
+float tinymt32_generate_float12(tinymt32_t *random);
+
+/*
+ This function outputs floating point number r (0.0 <= r < 1.0).
+ */
+float tinymt32_generate_float01(tinymt32_t *random);
+
+/*
+ This function outputs floating point number r (0.0 < r <= 1.0).
+ */
+float tinymt32_generate_floatOC(tinymt32_t *random);
+
+/*
+ This function outputs floating point number r (0.0 < r < 1.0).
+ */
+float tinymt32_generate_floatOO(tinymt32_t *random);
+
+/*
+ This function outputs double precision floating point number r (0.0 <= r < 1.0) with 32-bit precision.
+ */
+double tinymt32_generate_32double(tinymt32_t *random);
+
+/*
+ This function initializes the internal state array with a 64-bit unsigned integer seed.
+ */
+void tinymt64_init(tinymt64_t *random, uint64_t seed);
+
+/*
+ This function initializes the internal state array, with an array of 64-bit unsigned integers used as seeds.
+ */
+void tinymt64_init_by_array(tinymt64_t *random,
+                            const uint64_t *init_key,
+                            int key_length);
+
+/*
+ This function always returns 127.
+ */
+int tinymt64_get_mexp(tinymt64_t *random);
+
+/*
+ This function changes internal state of tinymt64.
+ */
+void tinymt64_next_state(tinymt64_t *random);
+
+/*
+ This function outputs 64-bit unsigned integer from internal state.
+ */
+uint64_t tinymt64_temper(tinymt64_t *random);
+
+/*
+ This function outputs floating point number r (1.0 <= r < 2.0) from internal state.
+ */
+double tinymt64_temper_conv(tinymt64_t *random);
+
+/*
+ This function outputs floating point number r (1.0 < r < 2.0) from internal state.
+ */
+double tinymt64_temper_conv_open(tinymt64_t *random);
+
+/*
+ This function outputs 64-bit unsigned integer r (0 <= r < 2^64).
+ */
+uint64_t tinymt64_generate_uint64(tinymt64_t *random);
+
+/*
+ This function outputs floating point number r (0.0 <= r < 1.0).
+ */
+double tinymt64_generate_double(tinymt64_t *random);
+
+/*
+ This function outputs floating point number r (0.0 <= r < 1.0).
+ */
+double tinymt64_generate_double01(tinymt64_t *random);
+
+/*
+ This function outputs floating point number r (1.0 <= r < 2.0).
+ */
+double tinymt64_generate_double12(tinymt64_t *random);
+
+/*
+ This function outputs floating point number r (0.0 < r <= 1.0).
+ */
+double tinymt64_generate_doubleOC(tinymt64_t *random);
+
+/*
+ This function outputs floating point number r (0.0 < r < 1.0).
+ */
+double tinymt64_generate_doubleOO(tinymt64_t *random);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* TINYMT_H */
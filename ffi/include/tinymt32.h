@@ -0,0 +1,7 @@
+/* Drop-in replacement of tinymt32.h of TinyMT 1.1.1, backed by the tinymt-ffi library. */
+#ifndef TINYMT32_H
+#define TINYMT32_H
+
+#include "tinymt.h"
+
+#endif /* TINYMT32_H */
@@ -0,0 +1,7 @@
+/* Drop-in replacement of tinymt64.h of TinyMT 1.1.1, backed by the tinymt-ffi library. */
+#ifndef TINYMT64_H
+#define TINYMT64_H
+
+#include "tinymt.h"
+
+#endif /* TINYMT64_H */
@@ -0,0 +1,309 @@
+//! C ABI of TinyMT 64/32 compatible with `tinymt32.h` and `tinymt64.h` of the original
+//! TinyMT 1.1.1, so that C code written for the original implementation can link this library
+//! unchanged. The header `include/tinymt.h` is generated from this crate by cbindgen with
+//! `cbindgen --config cbindgen.toml --output include/tinymt.h` in this directory, and
+//! `include/tinymt32.h` and `include/tinymt64.h` include it under the original names. A test
+//! fails if the checked-in header is stale, and another compiles `tests/c/check.c` against it and
+//! links it with the staticlib.
+//!
+//! Unlike the original headers, the functions declared as `inline static` there, such as
+//! `tinymt32_generate_uint32()`, are exported as ordinary functions.
+#![allow(non_camel_case_types, non_snake_case)]
+
+use core::mem::{align_of, size_of};
+use core::slice;
+use std::os::raw::{c_double, c_float, c_int};
+
+use tinymt::{TinyMT32, TinyMT64};
+
+pub const TINYMT32_MEXP: c_int = 127;
+pub const TINYMT32_SH0: c_int = 1;
+pub const TINYMT32_SH1: c_int = 10;
+pub const TINYMT32_SH8: c_int = 8;
+pub const TINYMT32_MASK: u32 = 0x7fff_ffff;
+// float as in tinymt32.h; defined in the header by cbindgen.toml, since cbindgen drops the suffixes
+pub const TINYMT32_MUL: c_float = 1.0 / 16_777_216.0;
+
+pub const TINYMT64_MEXP: c_int = 127;
+pub const TINYMT64_SH0: c_int = 12;
+pub const TINYMT64_SH1: c_int = 11;
+pub const TINYMT64_SH8: c_int = 8;
+pub const TINYMT64_MASK: u64 = 0x7fff_ffff_ffff_ffff;
+pub const TINYMT64_MUL: c_double = 1.0 / 9_007_199_254_740_992.0;
+
+/// tinymt32 internal state vector and parameters
+#[repr(C)]
+pub struct TINYMT32_T {
+  pub status: [u32; 4],
+  pub mat1: u32,
+  pub mat2: u32,
+  pub tmat: u32,
+}
+
+pub type tinymt32_t = TINYMT32_T;
+
+/// tinymt64 internal state vector and parameters
+#[repr(C)]
+pub struct TINYMT64_T {
+  pub status: [u64; 2],
+  pub mat1: u32,
+  pub mat2: u32,
+  pub tmat: u64,
+}
+
+pub type tinymt64_t = TINYMT64_T;
+
+// `TinyMT32` and `TinyMT64` are `repr(C)` with the same fields as the structs above.
+const _: () = assert!(size_of::<tinymt32_t>() == size_of::<TinyMT32>());
+const _: () = assert!(align_of::<tinymt32_t>() == align_of::<TinyMT32>());
+const _: () = assert!(size_of::<tinymt64_t>() == size_of::<TinyMT64>());
+const _: () = assert!(align_of::<tinymt64_t>() == align_of::<TinyMT64>());
+
+/// Reinterpret the C struct as the Rust one.
+/// # Safety
+/// `random` must point to a valid, properly aligned `tinymt32_t`.
+unsafe fn tinymt32<'a>(random: *mut tinymt32_t) -> &'a mut TinyMT32 {
+  &mut *(random as *mut TinyMT32)
+}
+
+/// Reinterpret the C struct as the Rust one.
+/// # Safety
+/// `random` must point to a valid, properly aligned `tinymt64_t`.
+unsafe fn tinymt64<'a>(random: *mut tinymt64_t) -> &'a mut TinyMT64 {
+  &mut *(random as *mut TinyMT64)
+}
+
+/// Convert the C array to a slice; a non-positive length is treated as an empty array.
+/// # Safety
+/// `init_key` must point to `key_length` readable elements if `key_length` is positive.
+unsafe fn init_key<'a, T>(init_key: *const T, key_length: c_int) -> &'a [T] {
+  if key_length <= 0 || init_key.is_null() {
+    &[]
+  } else {
+    slice::from_raw_parts(init_key, key_length as usize)
+  }
+}
+
+/// This function initializes the internal state array with a 32-bit unsigned integer seed.
+/// # Safety
+/// `random` must point to a valid `tinymt32_t`.
+#[no_mangle]
+pub unsafe extern "C" fn tinymt32_init(random: *mut tinymt32_t, seed: u32) {
+  tinymt::tinymt32::tinymt32_init(tinymt32(random), seed);
+}
+
+/// This function initializes the internal state array, with an array of 32-bit unsigned integers used as seeds.
+/// # Safety
+/// `random` must point to a valid `tinymt32_t` and `init_key` to `key_length` elements.
+#[no_mangle]
+pub unsafe extern "C" fn tinymt32_init_by_array(
+  random: *mut tinymt32_t,
+  init_key: *const u32,
+  key_length: c_int,
+) {
+  tinymt::tinymt32::tinymt32_init_by_array(tinymt32(random), self::init_key(init_key, key_length));
+}
+
+/// This function always returns 127.
+/// # Safety
+/// `random` must point to a valid `tinymt32_t`.
+#[no_mangle]
+pub unsafe extern "C" fn tinymt32_get_mexp(random: *mut tinymt32_t) -> c_int {
+  tinymt::tinymt32::tinymt32_get_mexp(tinymt32(random)) as c_int
+}
+
+/// This function changes internal state of tinymt32.
+/// # Safety
+/// `random` must point to a valid `tinymt32_t`.
+#[no_mangle]
+pub unsafe extern "C" fn tinymt32_next_state(random: *mut tinymt32_t) {
+  tinymt::tinymt32::tinymt32_next_state(tinymt32(random));
+}
+
+/// This function outputs 32-bit unsigned integer from internal state.
+/// # Safety
+/// `random` must point to a valid `tinymt32_t`.
+#[no_mangle]
+pub unsafe extern "C" fn tinymt32_temper(random: *mut tinymt32_t) -> u32 {
+  tinymt::tinymt32::tinymt32_temper(tinymt32(random))
+}
+
+/// This function outputs floating point number r (1.0 <= r < 2.0) from internal state.
+/// # Safety
+/// `random` must point to a valid `tinymt32_t`.
+#[no_mangle]
+pub unsafe extern "C" fn tinymt32_temper_conv(random: *mut tinymt32_t) -> c_float {
+  tinymt::tinymt32::tinymt32_temper_conv(tinymt32(random))
+}
+
+/// This function outputs floating point number r (1.0 < r < 2.0) from internal state.
+/// # Safety
+/// `random` must point to a valid `tinymt32_t`.
+#[no_mangle]
+pub unsafe extern "C" fn tinymt32_temper_conv_open(random: *mut tinymt32_t) -> c_float {
+  tinymt::tinymt32::tinymt32_temper_conv_open(tinymt32(random))
+}
+
+/// This function outputs 32-bit unsigned integer r (0 <= r < 2^32).
+/// # Safety
+/// `random` must point to a valid `tinymt32_t`.
+#[no_mangle]
+pub unsafe extern "C" fn tinymt32_generate_uint32(random: *mut tinymt32_t) -> u32 {
+  tinymt::tinymt32::tinymt32_generate_uint32(tinymt32(random))
+}
+
+/// This function outputs floating point number r (0.0 <= r < 1.0).
+/// # Safety
+/// `random` must point to a valid `tinymt32_t`.
+#[no_mangle]
+pub unsafe extern "C" fn tinymt32_generate_float(random: *mut tinymt32_t) -> c_float {
+  tinymt::tinymt32::tinymt32_generate_float(tinymt32(random))
+}
+
+/// This function outputs floating point number r (1.0 <= r < 2.0).
+/// # Safety
+/// `random` must point to a valid `tinymt32_t`.
+#[no_mangle]
+pub unsafe extern "C" fn tinymt32_generate_float12(random: *mut tinymt32_t) -> c_float {
+  tinymt::tinymt32::tinymt32_generate_float12(tinymt32(random))
+}
+
+/// This function outputs floating point number r (0.0 <= r < 1.0).
+/// # Safety
+/// `random` must point to a valid `tinymt32_t`.
+#[no_mangle]
+pub unsafe extern "C" fn tinymt32_generate_float01(random: *mut tinymt32_t) -> c_float {
+  tinymt::tinymt32::tinymt32_generate_float01(tinymt32(random))
+}
+
+/// This function outputs floating point number r (0.0 < r <= 1.0).
+/// # Safety
+/// `random` must point to a valid `tinymt32_t`.
+#[no_mangle]
+pub unsafe extern "C" fn tinymt32_generate_floatOC(random: *mut tinymt32_t) -> c_float {
+  tinymt::tinymt32::tinymt32_generate_float_oc(tinymt32(random))
+}
+
+/// This function outputs floating point number r (0.0 < r < 1.0).
+/// # Safety
+/// `random` must point to a valid `tinymt32_t`.
+#[no_mangle]
+pub unsafe extern "C" fn tinymt32_generate_floatOO(random: *mut tinymt32_t) -> c_float {
+  tinymt::tinymt32::tinymt32_generate_float_oo(tinymt32(random))
+}
+
+/// This function outputs double precision floating point number r (0.0 <= r < 1.0) with 32-bit precision.
+/// # Safety
+/// `random` must point to a valid `tinymt32_t`.
+#[no_mangle]
+pub unsafe extern "C" fn tinymt32_generate_32double(random: *mut tinymt32_t) -> c_double {
+  tinymt::tinymt32::tinymt32_generate_32double(tinymt32(random))
+}
+
+/// This function initializes the internal state array with a 64-bit unsigned integer seed.
+/// # Safety
+/// `random` must point to a valid `tinymt64_t`.
+#[no_mangle]
+pub unsafe extern "C" fn tinymt64_init(random: *mut tinymt64_t, seed: u64) {
+  tinymt::tinymt64::tinymt64_init(tinymt64(random), seed);
+}
+
+/// This function initializes the internal state array, with an array of 64-bit unsigned integers used as seeds.
+/// # Safety
+/// `random` must point to a valid `tinymt64_t` and `init_key` to `key_length` elements.
+#[no_mangle]
+pub unsafe extern "C" fn tinymt64_init_by_array(
+  random: *mut tinymt64_t,
+  init_key: *const u64,
+  key_length: c_int,
+) {
+  tinymt::tinymt64::tinymt64_init_by_array(tinymt64(random), self::init_key(init_key, key_length));
+}
+
+/// This function always returns 127.
+/// # Safety
+/// `random` must point to a valid `tinymt64_t`.
+#[no_mangle]
+pub unsafe extern "C" fn tinymt64_get_mexp(random: *mut tinymt64_t) -> c_int {
+  tinymt::tinymt64::tinymt64_get_mexp(tinymt64(random)) as c_int
+}
+
+/// This function changes internal state of tinymt64.
+/// # Safety
+/// `random` must point to a valid `tinymt64_t`.
+#[no_mangle]
+pub unsafe extern "C" fn tinymt64_next_state(random: *mut tinymt64_t) {
+  tinymt::tinymt64::tinymt64_next_state(tinymt64(random));
+}
+
+/// This function outputs 64-bit unsigned integer from internal state.
+/// # Safety
+/// `random` must point to a valid `tinymt64_t`.
+#[no_mangle]
+pub unsafe extern "C" fn tinymt64_temper(random: *mut tinymt64_t) -> u64 {
+  tinymt::tinymt64::tinymt64_temper(tinymt64(random))
+}
+
+/// This function outputs floating point number r (1.0 <= r < 2.0) from internal state.
+/// # Safety
+/// `random` must point to a valid `tinymt64_t`.
+#[no_mangle]
+pub unsafe extern "C" fn tinymt64_temper_conv(random: *mut tinymt64_t) -> c_double {
+  tinymt::tinymt64::tinymt64_temper_conv(tinymt64(random))
+}
+
+/// This function outputs floating point number r (1.0 < r < 2.0) from internal state.
+/// # Safety
+/// `random` must point to a valid `tinymt64_t`.
+#[no_mangle]
+pub unsafe extern "C" fn tinymt64_temper_conv_open(random: *mut tinymt64_t) -> c_double {
+  tinymt::tinymt64::tinymt64_temper_conv_open(tinymt64(random))
+}
+
+/// This function outputs 64-bit unsigned integer r (0 <= r < 2^64).
+/// # Safety
+/// `random` must point to a valid `tinymt64_t`.
+#[no_mangle]
+pub unsafe extern "C" fn tinymt64_generate_uint64(random: *mut tinymt64_t) -> u64 {
+  tinymt::tinymt64::tinymt64_generate_uint64(tinymt64(random))
+}
+
+/// This function outputs floating point number r (0.0 <= r < 1.0).
+/// # Safety
+/// `random` must point to a valid `tinymt64_t`.
+#[no_mangle]
+pub unsafe extern "C" fn tinymt64_generate_double(random: *mut tinymt64_t) -> c_double {
+  tinymt::tinymt64::tinymt64_generate_double(tinymt64(random))
+}
+
+/// This function outputs floating point number r (0.0 <= r < 1.0).
+/// # Safety
+/// `random` must point to a valid `tinymt64_t`.
+#[no_mangle]
+pub unsafe extern "C" fn tinymt64_generate_double01(random: *mut tinymt64_t) -> c_double {
+  tinymt::tinymt64::tinymt64_generate_double01(tinymt64(random))
+}
+
+/// This function outputs floating point number r (1.0 <= r < 2.0).
+/// # Safety
+/// `random` must point to a valid `tinymt64_t`.
+#[no_mangle]
+pub unsafe extern "C" fn tinymt64_generate_double12(random: *mut tinymt64_t) -> c_double {
+  tinymt::tinymt64::tinymt64_generate_double12(tinymt64(random))
+}
+
+/// This function outputs floating point number r (0.0 < r <= 1.0).
+/// # Safety
+/// `random` must point to a valid `tinymt64_t`.
+#[no_mangle]
+pub unsafe extern "C" fn tinymt64_generate_doubleOC(random: *mut tinymt64_t) -> c_double {
+  tinymt::tinymt64::tinymt64_generate_double_oc(tinymt64(random))
+}
+
+/// This function outputs floating point number r (0.0 < r < 1.0).
+/// # Safety
+/// `random` must point to a valid `tinymt64_t`.
+#[no_mangle]
+pub unsafe extern "C" fn tinymt64_generate_doubleOO(random: *mut tinymt64_t) -> c_double {
+  tinymt::tinymt64::tinymt64_generate_double_oo(tinymt64(random))
+}
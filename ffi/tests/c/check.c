@@ -0,0 +1,68 @@
+/* Compiled against include/tinymt32.h and include/tinymt64.h, and linked with the staticlib by
+ * tests/c_abi.rs. Exits with a non-zero status on the first mismatch. */
+#include <stddef.h>
+#include <stdio.h>
+#include "tinymt32.h"
+#include "tinymt64.h"
+
+_Static_assert(sizeof(tinymt32_t) == 28, "size of tinymt32_t");
+_Static_assert(offsetof(tinymt32_t, tmat) == 24, "offset of tinymt32_t.tmat");
+_Static_assert(sizeof(tinymt64_t) == 32, "size of tinymt64_t");
+_Static_assert(offsetof(tinymt64_t, tmat) == 24, "offset of tinymt64_t.tmat");
+_Static_assert(_Generic(TINYMT32_MUL, float: 1, default: 0), "TINYMT32_MUL is float");
+_Static_assert(_Generic(TINYMT64_MUL, double: 1, default: 0), "TINYMT64_MUL is double");
+
+#define CHECK(cond)                                          \
+  do {                                                       \
+    if (!(cond)) {                                           \
+      printf("%s:%d: %s\n", __FILE__, __LINE__, #cond);      \
+      return 1;                                              \
+    }                                                        \
+  } while (0)
+
+/* https://github.com/MersenneTwister-Lab/TinyMT/blob/master/tinymt/check32.c */
+static int check32(void) {
+  static const uint32_t expected[] = {2545341989u, 981918433u, 3715302833u, 2387538352u,
+                                      3591001365u};
+  tinymt32_t tinymt = {{0, 0, 0, 0}, 0x8f7011ee, 0xfc78ff1f, 0x3793fdff};
+  tinymt32_t copy;
+  uint32_t seed_array[] = {1};
+  int i;
+  CHECK(tinymt32_get_mexp(&tinymt) == TINYMT32_MEXP);
+  tinymt32_init(&tinymt, 1);
+  for (i = 0; i < 5; i++) {
+    CHECK(tinymt32_generate_uint32(&tinymt) == expected[i]);
+  }
+  tinymt32_init_by_array(&tinymt, seed_array, 1);
+  copy = tinymt;
+  for (i = 0; i < 100; i++) {
+    CHECK(tinymt32_generate_float(&tinymt) == (tinymt32_generate_uint32(&copy) >> 8) * TINYMT32_MUL);
+  }
+  return 0;
+}
+
+/* https://github.com/MersenneTwister-Lab/TinyMT/blob/master/tinymt/check64.c */
+static int check64(void) {
+  static const uint64_t expected[] = {UINT64_C(15503804787016557143),
+                                      UINT64_C(17280942441431881838),
+                                      UINT64_C(2177846447079362065)};
+  tinymt64_t tinymt = {{0, 0}, 0xfa051f40, 0xffd0fff4, UINT64_C(0x58d02ffeffbfffbc)};
+  tinymt64_t copy;
+  uint64_t seed_array[] = {1};
+  int i;
+  CHECK(tinymt64_get_mexp(&tinymt) == TINYMT64_MEXP);
+  tinymt64_init(&tinymt, 1);
+  for (i = 0; i < 3; i++) {
+    CHECK(tinymt64_generate_uint64(&tinymt) == expected[i]);
+  }
+  tinymt64_init_by_array(&tinymt, seed_array, 1);
+  copy = tinymt;
+  for (i = 0; i < 100; i++) {
+    CHECK(tinymt64_generate_double(&tinymt) == (tinymt64_generate_uint64(&copy) >> 11) * TINYMT64_MUL);
+  }
+  return 0;
+}
+
+int main(void) {
+  return check32() || check64();
+}
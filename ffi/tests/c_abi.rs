@@ -0,0 +1,31 @@
+//! Compile `tests/c/check.c` against the checked-in headers with the C compiler `$CC` (`cc` by
+//! default) and link it with the staticlib, so a drift of the header from the exported ABI fails.
+#![cfg(all(unix, not(target_family = "wasm")))]
+use std::path::PathBuf;
+use std::process::Command;
+
+#[test]
+fn test_c_program_with_staticlib() {
+  let crate_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+  // the staticlib is built in the parent directory of the deps/ directory of this executable
+  let exe = std::env::current_exe().unwrap();
+  let target_dir = exe.parent().unwrap().parent().unwrap();
+  let staticlib = target_dir.join("libtinymt_ffi.a");
+  assert!(staticlib.exists(), "{} is not built", staticlib.display());
+  let program = target_dir.join("tinymt_ffi_check");
+
+  let cc = std::env::var("CC").unwrap_or_else(|_| "cc".to_string());
+  let output = Command::new(&cc)
+    .args(["-std=c11", "-Wall", "-Wextra", "-Werror", "-I"])
+    .arg(crate_dir.join("include"))
+    .arg(crate_dir.join("tests").join("c").join("check.c"))
+    .arg(&staticlib)
+    .args(["-lpthread", "-ldl", "-lm", "-o"])
+    .arg(&program)
+    .output()
+    .unwrap_or_else(|e| panic!("failed to run {}: {}", cc, e));
+  assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+  let output = Command::new(&program).output().unwrap();
+  assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stdout));
+}
@@ -0,0 +1,46 @@
+use tinymt_ffi::*;
+
+/// https://github.com/MersenneTwister-Lab/TinyMT/blob/master/tinymt/check32.c
+#[test]
+fn test_tinymt32_by_c_abi() {
+  let mut tinymt =
+    tinymt32_t { status: [0; 4], mat1: 0x8f7011ee, mat2: 0xfc78ff1f, tmat: 0x3793fdff };
+  unsafe {
+    assert_eq!(TINYMT32_MEXP, tinymt32_get_mexp(&mut tinymt));
+    tinymt32_init(&mut tinymt, 1);
+    for expected in [2545341989, 981918433, 3715302833, 2387538352, 3591001365] {
+      assert_eq!(expected, tinymt32_generate_uint32(&mut tinymt));
+    }
+    let seed_array: [u32; 1] = [1];
+    tinymt32_init_by_array(&mut tinymt, seed_array.as_ptr(), seed_array.len() as i32);
+    for expected in [0.0132459, 0.2083899, 0.1457998, 0.1144078, 0.6173239] {
+      assert_eq!(
+        format!("{:.7}", expected),
+        format!("{:.7}", tinymt32_generate_float(&mut tinymt))
+      );
+    }
+    tinymt32_init_by_array(&mut tinymt, std::ptr::null(), 0);
+    assert!((0.0..1.0).contains(&tinymt32_generate_floatOO(&mut tinymt)));
+  }
+}
+
+/// https://github.com/MersenneTwister-Lab/TinyMT/blob/master/tinymt/check64.c
+#[test]
+fn test_tinymt64_by_c_abi() {
+  let mut tinymt =
+    tinymt64_t { status: [0; 2], mat1: 0xfa051f40, mat2: 0xffd0fff4, tmat: 0x58d02ffeffbfffbc };
+  unsafe {
+    assert_eq!(TINYMT64_MEXP, tinymt64_get_mexp(&mut tinymt));
+    tinymt64_init(&mut tinymt, 1);
+    for expected in [15503804787016557143, 17280942441431881838, 2177846447079362065] {
+      assert_eq!(expected, tinymt64_generate_uint64(&mut tinymt));
+    }
+    let seed_array: [u64; 1] = [1];
+    tinymt64_init_by_array(&mut tinymt, seed_array.as_ptr(), seed_array.len() as i32);
+    for expected in
+      [0.1255671232295209, 0.8182624006077499, 0.30822110203281683, 0.8255918229908551]
+    {
+      assert_eq!(expected, tinymt64_generate_double(&mut tinymt));
+    }
+  }
+}
@@ -0,0 +1,19 @@
+use std::path::PathBuf;
+
+/// The checked-in `include/tinymt.h` must be the same as the header cbindgen generates from the
+/// current source.
+#[test]
+fn test_header_is_up_to_date() {
+  let crate_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+  let config = cbindgen::Config::from_file(crate_dir.join("cbindgen.toml")).unwrap();
+  let mut generated = Vec::new();
+  cbindgen::generate_with_config(&crate_dir, config)
+    .expect("failed to generate the C header")
+    .write(&mut generated);
+  let checked_in = std::fs::read(crate_dir.join("include").join("tinymt.h")).unwrap();
+  assert!(
+    generated == checked_in,
+    "include/tinymt.h is stale; run `cbindgen --config cbindgen.toml --output include/tinymt.h` \
+     in ffi/"
+  );
+}
@@ -0,0 +1,161 @@
+//! Procedural macros that expand to random numbers generated by TinyMT at build time, so that
+//! reproducible random tables can be embedded without any runtime cost.
+//!
+//! ```rust
+//! use tinymt_macros::{tinymt_array, tinymt_value};
+//!
+//! static KEYS: [u64; 1024] = tinymt_array!(u64, 1024, seed = 42);
+//! const THRESHOLD: f64 = tinymt_value!(f64, seed = 7, index = 3);
+//! assert!((0.0..1.0).contains(&THRESHOLD));
+//! ```
+//!
+//! `u64` and `f64` values are generated by `tinymt64_generate_uint64()` and
+//! `tinymt64_generate_double()` of `TinyMT64`, `u32` and `f32` values by
+//! `tinymt32_generate_uint32()` and `tinymt32_generate_float()` of `TinyMT32`. The generator is
+//! initialized by `tinymt64_init()` or `tinymt32_init()` with the `seed` and the parameter set
+//! of id 0 unless `params = (mat1, mat2, tmat)` is specified.
+use proc_macro::TokenStream;
+use proc_macro2::{Literal, Span};
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{parenthesized, parse_macro_input, Error, Ident, LitInt, Token};
+
+use tinymt::tinymt32::{tinymt32_generate_float, tinymt32_generate_uint32};
+use tinymt::tinymt64::{tinymt64_generate_double, tinymt64_generate_uint64};
+use tinymt::{TinyMT32, TinyMT64};
+
+/// Expand to an array literal of random numbers, e.g. `tinymt_array!(u64, 1024, seed = 42)`.
+/// The element type is one of `u32`, `u64`, `f32` or `f64`.
+#[proc_macro]
+pub fn tinymt_array(input: TokenStream) -> TokenStream {
+  let args = parse_macro_input!(input as Arguments);
+  let length = match &args.length {
+    Some(length) => length,
+    None => {
+      return Error::new(Span::call_site(), "the array length is required")
+        .into_compile_error()
+        .into()
+    }
+  };
+  let expanded = length.base10_parse::<usize>().and_then(|length| {
+    let mut generator = Generator::new(&args)?;
+    let elements = (0..length).map(|_| generator.next()).collect::<Vec<_>>();
+    Ok(quote! { [#(#elements),*] })
+  });
+  expanded.unwrap_or_else(Error::into_compile_error).into()
+}
+
+/// Expand to a literal of the random number at the specified position of the sequence, e.g.
+/// `tinymt_value!(f64, seed = 7, index = 3)`. The type is one of `u32`, `u64`, `f32` or `f64`.
+#[proc_macro]
+pub fn tinymt_value(input: TokenStream) -> TokenStream {
+  let args = parse_macro_input!(input as Arguments);
+  if let Some(length) = &args.length {
+    return Error::new(length.span(), "unexpected array length").into_compile_error().into();
+  }
+  let expanded = Generator::new(&args).map(|mut generator| {
+    for _ in 0..args.index {
+      generator.next();
+    }
+    let value = generator.next();
+    quote! { #value }
+  });
+  expanded.unwrap_or_else(Error::into_compile_error).into()
+}
+
+/// Arguments of the macros: `TYPE [, LENGTH] (, NAME = VALUE)*`
+struct Arguments {
+  ty: Ident,
+  length: Option<LitInt>,
+  seed: Option<LitInt>,
+  index: u64,
+  params: Option<[LitInt; 3]>,
+}
+
+impl Parse for Arguments {
+  fn parse(input: ParseStream) -> syn::Result<Self> {
+    let ty: Ident = input.parse()?;
+    let mut args = Arguments { ty, length: None, seed: None, index: 0, params: None };
+    if input.is_empty() {
+      return Ok(args);
+    }
+    input.parse::<Token![,]>()?;
+    if input.peek(LitInt) {
+      args.length = Some(input.parse()?);
+      if input.is_empty() {
+        return Ok(args);
+      }
+      input.parse::<Token![,]>()?;
+    }
+    while !input.is_empty() {
+      let name: Ident = input.parse()?;
+      input.parse::<Token![=]>()?;
+      match name.to_string().as_str() {
+        "seed" => args.seed = Some(input.parse()?),
+        "index" => args.index = input.parse::<LitInt>()?.base10_parse()?,
+        "params" => {
+          let content;
+          parenthesized!(content in input);
+          let params = Punctuated::<LitInt, Token![,]>::parse_terminated(&content)?;
+          let params = params.into_iter().collect::<Vec<_>>();
+          let params: [LitInt; 3] = params
+            .try_into()
+            .map_err(|_| Error::new(name.span(), "params must be a tuple of (mat1, mat2, tmat)"))?;
+          args.params = Some(params);
+        }
+        _ => return Err(Error::new(name.span(), format!("unknown argument: {}", name))),
+      }
+      if input.is_empty() {
+        break;
+      }
+      input.parse::<Token![,]>()?;
+    }
+    Ok(args)
+  }
+}
+
+/// The generator of the literals of the type specified in the macro arguments.
+enum Generator {
+  U32(TinyMT32),
+  U64(TinyMT64),
+  F32(TinyMT32),
+  F64(TinyMT64),
+}
+
+impl Generator {
+  fn new(args: &Arguments) -> syn::Result<Generator> {
+    let seed = args.seed.as_ref().ok_or_else(|| Error::new(args.ty.span(), "seed is required"))?;
+    let build_error = |e: tinymt::BuildError| Error::new(args.ty.span(), e.to_string());
+    let tinymt32 = || {
+      let mut builder = TinyMT32::builder().seed(seed.base10_parse()?);
+      if let Some([mat1, mat2, tmat]) = &args.params {
+        builder = builder.params(mat1.base10_parse()?, mat2.base10_parse()?, tmat.base10_parse()?);
+      }
+      builder.build().map_err(build_error)
+    };
+    let tinymt64 = || {
+      let mut builder = TinyMT64::builder().seed(seed.base10_parse()?);
+      if let Some([mat1, mat2, tmat]) = &args.params {
+        builder = builder.params(mat1.base10_parse()?, mat2.base10_parse()?, tmat.base10_parse()?);
+      }
+      builder.build().map_err(build_error)
+    };
+    match args.ty.to_string().as_str() {
+      "u32" => Ok(Generator::U32(tinymt32()?)),
+      "u64" => Ok(Generator::U64(tinymt64()?)),
+      "f32" => Ok(Generator::F32(tinymt32()?)),
+      "f64" => Ok(Generator::F64(tinymt64()?)),
+      ty => Err(Error::new(args.ty.span(), format!("unsupported type: {}", ty))),
+    }
+  }
+
+  fn next(&mut self) -> Literal {
+    match self {
+      Generator::U32(random) => Literal::u32_suffixed(tinymt32_generate_uint32(random)),
+      Generator::U64(random) => Literal::u64_suffixed(tinymt64_generate_uint64(random)),
+      Generator::F32(random) => Literal::f32_suffixed(tinymt32_generate_float(random)),
+      Generator::F64(random) => Literal::f64_suffixed(tinymt64_generate_double(random)),
+    }
+  }
+}
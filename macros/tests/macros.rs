@@ -0,0 +1,47 @@
+use tinymt::tinymt32::{tinymt32_generate_float, tinymt32_generate_uint32};
+use tinymt::tinymt64::{tinymt64_generate_double, tinymt64_generate_uint64};
+use tinymt::{TinyMT32, TinyMT64};
+use tinymt_macros::{tinymt_array, tinymt_value};
+
+#[test]
+fn test_tinymt_array() {
+  static U64: [u64; 1024] = tinymt_array!(u64, 1024, seed = 42);
+  let mut random = TinyMT64::builder().seed(42).build().unwrap();
+  for expected in U64.iter() {
+    assert_eq!(*expected, tinymt64_generate_uint64(&mut random));
+  }
+
+  const F64: [f64; 16] = tinymt_array!(f64, 16, seed = 42);
+  let mut random = TinyMT64::builder().seed(42).build().unwrap();
+  for expected in F64.iter() {
+    assert_eq!(expected.to_bits(), tinymt64_generate_double(&mut random).to_bits());
+  }
+
+  const U32: [u32; 16] =
+    tinymt_array!(u32, 16, seed = 1, params = (0x8f7011ee, 0xfc78ff1f, 0x3793fdff));
+  assert_eq!([2545341989, 981918433, 3715302833, 2387538352, 3591001365], U32[..5]);
+
+  const F32: [f32; 16] = tinymt_array!(f32, 16, seed = 7);
+  let mut random = TinyMT32::builder().seed(7).build().unwrap();
+  for expected in F32.iter() {
+    assert_eq!(expected.to_bits(), tinymt32_generate_float(&mut random).to_bits());
+  }
+
+  const EMPTY: [u64; 0] = tinymt_array!(u64, 0, seed = 0);
+  assert!(EMPTY.is_empty());
+}
+
+#[test]
+fn test_tinymt_value() {
+  const U64: u64 =
+    tinymt_value!(u64, seed = 1, params = (0xfa051f40, 0xffd0fff4, 0x58d02ffeffbfffbc), index = 2);
+  assert_eq!(2177846447079362065, U64);
+
+  const F64: f64 = tinymt_value!(f64, seed = 7, index = 3);
+  let mut random = TinyMT64::builder().seed(7).skip(3).build().unwrap();
+  assert_eq!(tinymt64_generate_double(&mut random).to_bits(), F64.to_bits());
+
+  const U32: u32 = tinymt_value!(u32, seed = 7);
+  let mut random = TinyMT32::builder().seed(7).build().unwrap();
+  assert_eq!(tinymt32_generate_uint32(&mut random), U32);
+}
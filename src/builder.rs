@@ -0,0 +1,239 @@
+use core::fmt::{Display, Formatter};
+
+use crate::tinymt32::{
+  tinymt32_has_maximal_period, tinymt32_init, tinymt32_init_by_array, tinymt32_jump,
+  tinymt32_next_state,
+};
+use crate::tinymt64::{
+  tinymt64_has_maximal_period, tinymt64_init, tinymt64_init_by_array, tinymt64_jump,
+  tinymt64_next_state,
+};
+use crate::{TinyMT32, TinyMT64};
+
+/// The TinyMT32 parameter sets (mat1, mat2, tmat) bundled with this crate, indexed by parameter
+/// id. The id 0 is the parameter set used in check32.c of the original implementation and in
+/// RFC 8682. More sets are to be copied from the published output of tinymt32dc, in order; the
+/// tests check that each of them gives the maximal period.
+pub(crate) const TINYMT32_PARAMS: [(u32, u32, u32); 1] = [(0x8f70_11ee, 0xfc78_ff1f, 0x3793_fdff)];
+
+/// The TinyMT64 parameter sets (mat1, mat2, tmat) bundled with this crate, indexed by parameter
+/// id. The id 0 is the parameter set used in check64.c of the original implementation. More sets
+/// are to be copied from the published output of tinymt64dc, in order; the tests check that each
+/// of them gives the maximal period.
+pub(crate) const TINYMT64_PARAMS: [(u32, u32, u64); 1] =
+  [(0xfa05_1f40, 0xffd0_fff4, 0x58d0_2ffe_ffbf_ffbc)];
+
+/// The reason why a builder couldn't construct a generator.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum BuildError {
+  /// Both `params()` and `param_id()` were specified.
+  ConflictingParams,
+  /// The specified parameter id isn't bundled with this crate.
+  UnknownParamId(u32),
+  /// The specified parameters don't give the maximal period 2^127-1.
+  DegenerateParams,
+  /// Both `seed()` and `seed_array()` were specified.
+  ConflictingSeeds,
+  /// Neither `seed()` nor `seed_array()` was specified.
+  MissingSeed,
+}
+
+impl Display for BuildError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    match self {
+      BuildError::ConflictingParams => write!(f, "both params and param_id are specified"),
+      BuildError::UnknownParamId(id) => write!(f, "unknown parameter id: {}", id),
+      BuildError::DegenerateParams => {
+        write!(f, "the parameters don't give the maximal period 2^127-1")
+      }
+      BuildError::ConflictingSeeds => write!(f, "both seed and seed_array are specified"),
+      BuildError::MissingSeed => write!(f, "neither seed nor seed_array is specified"),
+    }
+  }
+}
+
+/// Builder to configure a `TinyMT32`, created by `TinyMT32::builder()`.
+///
+/// ```rust
+/// use tinymt::TinyMT32;
+///
+/// let mut random = TinyMT32::builder().param_id(0).seed(1).build().unwrap();
+/// assert_eq!(2545341989, random.next_u32());
+/// ```
+///
+/// If no parameter set is specified, the one of parameter id 0 is used.
+#[derive(Debug, Clone, Default)]
+pub struct TinyMT32Builder<'a> {
+  params: Option<(u32, u32, u32)>,
+  param_id: Option<u32>,
+  seed: Option<u32>,
+  seed_array: Option<&'a [u32]>,
+  skip: u64,
+  jump: u128,
+}
+
+impl TinyMT32 {
+  /// Create a builder to configure a `TinyMT32`.
+  pub fn builder<'a>() -> TinyMT32Builder<'a> {
+    TinyMT32Builder::default()
+  }
+}
+
+impl<'a> TinyMT32Builder<'a> {
+  /// Use the specified parameter set. The parameters are verified to give the maximal period
+  /// 2^127-1 when building.
+  pub fn params(mut self, mat1: u32, mat2: u32, tmat: u32) -> Self {
+    self.params = Some((mat1, mat2, tmat));
+    self
+  }
+
+  /// Use the parameter set bundled with this crate by its id.
+  pub fn param_id(mut self, id: u32) -> Self {
+    self.param_id = Some(id);
+    self
+  }
+
+  /// Initialize the generator with a 32-bit seed as `tinymt32_init()`.
+  pub fn seed(mut self, seed: u32) -> Self {
+    self.seed = Some(seed);
+    self
+  }
+
+  /// Initialize the generator with an array of 32-bit seeds as `tinymt32_init_by_array()`.
+  pub fn seed_array(mut self, init_key: &'a [u32]) -> Self {
+    self.seed_array = Some(init_key);
+    self
+  }
+
+  /// Discard the first `n` outputs of the initialized generator one by one.
+  pub fn skip(mut self, n: u64) -> Self {
+    self.skip = n;
+    self
+  }
+
+  /// Jump ahead the initialized generator by `steps` outputs as `tinymt32_jump()`.
+  pub fn jump(mut self, steps: u128) -> Self {
+    self.jump = steps;
+    self
+  }
+
+  /// Construct a `TinyMT32` with the configuration of this builder.
+  pub fn build(&self) -> Result<TinyMT32, BuildError> {
+    let (mat1, mat2, tmat) = match (self.params, self.param_id) {
+      (Some(_), Some(_)) => return Err(BuildError::ConflictingParams),
+      (Some(params), None) => params,
+      (None, Some(id)) => {
+        *TINYMT32_PARAMS.get(id as usize).ok_or(BuildError::UnknownParamId(id))?
+      }
+      (None, None) => TINYMT32_PARAMS[0],
+    };
+    let mut random = TinyMT32::new([0, 0, 0, 0], mat1, mat2, tmat);
+    if self.params.is_some() && !tinymt32_has_maximal_period(&random) {
+      return Err(BuildError::DegenerateParams);
+    }
+    match (self.seed, self.seed_array) {
+      (Some(_), Some(_)) => return Err(BuildError::ConflictingSeeds),
+      (Some(seed), None) => tinymt32_init(&mut random, seed),
+      (None, Some(init_key)) => tinymt32_init_by_array(&mut random, init_key),
+      (None, None) => return Err(BuildError::MissingSeed),
+    }
+    tinymt32_jump(&mut random, self.jump);
+    for _ in 0..self.skip {
+      tinymt32_next_state(&mut random);
+    }
+    Ok(random)
+  }
+}
+
+/// Builder to configure a `TinyMT64`, created by `TinyMT64::builder()`.
+///
+/// ```rust
+/// use tinymt::TinyMT64;
+///
+/// let mut random = TinyMT64::builder().param_id(0).seed(1).build().unwrap();
+/// assert_eq!(15503804787016557143, random.next_u64());
+/// ```
+///
+/// If no parameter set is specified, the one of parameter id 0 is used.
+#[derive(Debug, Clone, Default)]
+pub struct TinyMT64Builder<'a> {
+  params: Option<(u32, u32, u64)>,
+  param_id: Option<u32>,
+  seed: Option<u64>,
+  seed_array: Option<&'a [u64]>,
+  skip: u64,
+  jump: u128,
+}
+
+impl TinyMT64 {
+  /// Create a builder to configure a `TinyMT64`.
+  pub fn builder<'a>() -> TinyMT64Builder<'a> {
+    TinyMT64Builder::default()
+  }
+}
+
+impl<'a> TinyMT64Builder<'a> {
+  /// Use the specified parameter set. The parameters are verified to give the maximal period
+  /// 2^127-1 when building.
+  pub fn params(mut self, mat1: u32, mat2: u32, tmat: u64) -> Self {
+    self.params = Some((mat1, mat2, tmat));
+    self
+  }
+
+  /// Use the parameter set bundled with this crate by its id.
+  pub fn param_id(mut self, id: u32) -> Self {
+    self.param_id = Some(id);
+    self
+  }
+
+  /// Initialize the generator with a 64-bit seed as `tinymt64_init()`.
+  pub fn seed(mut self, seed: u64) -> Self {
+    self.seed = Some(seed);
+    self
+  }
+
+  /// Initialize the generator with an array of 64-bit seeds as `tinymt64_init_by_array()`.
+  pub fn seed_array(mut self, init_key: &'a [u64]) -> Self {
+    self.seed_array = Some(init_key);
+    self
+  }
+
+  /// Discard the first `n` outputs of the initialized generator one by one.
+  pub fn skip(mut self, n: u64) -> Self {
+    self.skip = n;
+    self
+  }
+
+  /// Jump ahead the initialized generator by `steps` outputs as `tinymt64_jump()`.
+  pub fn jump(mut self, steps: u128) -> Self {
+    self.jump = steps;
+    self
+  }
+
+  /// Construct a `TinyMT64` with the configuration of this builder.
+  pub fn build(&self) -> Result<TinyMT64, BuildError> {
+    let (mat1, mat2, tmat) = match (self.params, self.param_id) {
+      (Some(_), Some(_)) => return Err(BuildError::ConflictingParams),
+      (Some(params), None) => params,
+      (None, Some(id)) => {
+        *TINYMT64_PARAMS.get(id as usize).ok_or(BuildError::UnknownParamId(id))?
+      }
+      (None, None) => TINYMT64_PARAMS[0],
+    };
+    let mut random = TinyMT64::new([0, 0], mat1, mat2, tmat);
+    if self.params.is_some() && !tinymt64_has_maximal_period(&random) {
+      return Err(BuildError::DegenerateParams);
+    }
+    match (self.seed, self.seed_array) {
+      (Some(_), Some(_)) => return Err(BuildError::ConflictingSeeds),
+      (Some(seed), None) => tinymt64_init(&mut random, seed),
+      (None, Some(init_key)) => tinymt64_init_by_array(&mut random, init_key),
+      (None, None) => return Err(BuildError::MissingSeed),
+    }
+    tinymt64_jump(&mut random, self.jump);
+    for _ in 0..self.skip {
+      tinymt64_next_state(&mut random);
+    }
+    Ok(random)
+  }
+}
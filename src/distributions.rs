@@ -0,0 +1,402 @@
+//! Probability distributions sampled with TinyMT, independent of `rand`.
+//!
+//! ```rust
+//! use tinymt::distributions::{Distribution, Normal};
+//! use tinymt::TinyMT64;
+//!
+//! let mut random = TinyMT64::from_seed_u64(1);
+//! let normal = Normal::new(10.0, 2.0).unwrap();
+//! let x: f64 = normal.sample(&mut random);
+//! assert!(x.is_finite());
+//! ```
+//!
+//! The samplers take uniform numbers in the open interval (0, 1) from `RandomSource::open01()`
+//! wherever the logarithm of them is taken, so they never evaluate `ln(0)`. The math functions of
+//! `libm` are used instead of those of the platform, so the samples for a given generator state
+//! are the same on every platform, and they are frozen: they won't change in any 1.x release.
+//!
+//! | distribution  | algorithm                                                     |
+//! |---------------|---------------------------------------------------------------|
+//! | `Uniform`     | `low + (high - low) u`, retried if rounded up to `high`       |
+//! | `Normal`      | 256-layer ziggurat (Marsaglia and Tsang) with Doornik's table |
+//! | `LogNormal`   | `exp(Normal)`                                                 |
+//! | `Exponential` | inversion, `-ln(u) / lambda`                                  |
+//! | `Gamma`       | Marsaglia and Tsang, with `Gamma(k + 1) u^(1/k)` for k < 1    |
+//! | `Beta`        | `X / (X + Y)` of `X = Gamma(a)` and `Y = Gamma(b)`, in log    |
+//! |               | space if a < 1 or b < 1                                       |
+//! | `Cauchy`      | inversion, `median + scale tan(pi (u - 1/2))`                 |
+//! | `Weibull`     | inversion, `scale (-ln(u))^(1/shape)`                         |
+//!
+//! The discrete distributions take bounded integers by Lemire's method wherever they can, and are
+//! frozen as well.
+//!
+//! | distribution     | algorithm                                                             |
+//! |------------------|-----------------------------------------------------------------------|
+//! | `Bernoulli`      | 64 random bits compared with an integer threshold                     |
+//! | `Binomial`       | BTPE (Kachitvichyanukul and Schmeiser) for np >= 10, inversion below  |
+//! | `Poisson`        | PTRS (Hörmann) for lambda >= 10, multiplication of uniforms below     |
+//! | `Geometric`      | inversion, `floor(ln(u) / ln(1 - p))`                                 |
+//! | `Hypergeometric` | H2PE (Kachitvichyanukul and Schmeiser) for a mode >= 10, HIN below    |
+//! | `AliasTable`     | Walker's alias method with Vose's construction and integer thresholds |
+use core::f64::consts::PI;
+use core::fmt::{Display, Formatter};
+
+use crate::tinymt32::{
+  tinymt32_generate_53double, tinymt32_generate_53double_oo, tinymt32_generate_uint32,
+};
+use crate::tinymt64::{
+  tinymt64_generate_double, tinymt64_generate_double_oo, tinymt64_generate_uint64,
+};
+use crate::{TinyMT32, TinyMT64};
+
+#[cfg(feature = "alloc")]
+pub use self::discrete::AliasTable;
+pub use self::discrete::{Bernoulli, BernoulliRatio, Binomial, Geometric, Hypergeometric, Poisson};
+use self::ziggurat_tables::{ZIG_NORM_F, ZIG_NORM_R, ZIG_NORM_X};
+
+mod discrete;
+mod ziggurat_tables;
+
+/// Source of uniform random numbers for the samplers.
+pub trait RandomSource {
+  /// Generate 64 random bits.
+  fn bits64(&mut self) -> u64;
+
+  /// Generate a floating point number r (0.0 < r < 1.0).
+  fn open01(&mut self) -> f64;
+
+  /// Generate a floating point number r (0.0 <= r < 1.0).
+  fn closed_open01(&mut self) -> f64;
+}
+
+/// `TinyMT64` uses one output for each number, by `tinymt64_generate_uint64()`,
+/// `tinymt64_generate_double_oo()` and `tinymt64_generate_double()`.
+impl RandomSource for TinyMT64 {
+  fn bits64(&mut self) -> u64 {
+    tinymt64_generate_uint64(self)
+  }
+
+  fn open01(&mut self) -> f64 {
+    tinymt64_generate_double_oo(self)
+  }
+
+  fn closed_open01(&mut self) -> f64 {
+    tinymt64_generate_double(self)
+  }
+}
+
+/// `TinyMT32` uses two outputs for each number: `(first << 32) | second`,
+/// `tinymt32_generate_53double_oo()` and `tinymt32_generate_53double()`.
+impl RandomSource for TinyMT32 {
+  fn bits64(&mut self) -> u64 {
+    let first = tinymt32_generate_uint32(self) as u64;
+    let second = tinymt32_generate_uint32(self) as u64;
+    (first << 32) | second
+  }
+
+  fn open01(&mut self) -> f64 {
+    tinymt32_generate_53double_oo(self)
+  }
+
+  fn closed_open01(&mut self) -> f64 {
+    tinymt32_generate_53double(self)
+  }
+}
+
+/// Probability distribution that generates samples of type `T`.
+pub trait Distribution<T> {
+  /// Generate a sample with the random numbers of `random`.
+  fn sample<R: RandomSource + ?Sized>(&self, random: &mut R) -> T;
+}
+
+/// The reason why a distribution couldn't be constructed with the specified parameters.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum DistributionError {
+  /// A parameter is NaN or infinite.
+  NotFinite,
+  /// A scale, shape or rate parameter is not positive.
+  NotPositive,
+  /// The lower bound of the interval is not less than the upper bound.
+  EmptyInterval,
+  /// A probability is not in [0, 1].
+  NotProbability,
+  /// A count exceeds the population or a denominator is 0.
+  InvalidCount,
+  /// The weights are empty, or contain a negative or non-finite weight, or sum to 0.
+  InvalidWeights,
+}
+
+impl Display for DistributionError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    match self {
+      DistributionError::NotFinite => write!(f, "a parameter is not finite"),
+      DistributionError::NotPositive => {
+        write!(f, "a scale, shape or rate parameter is not positive")
+      }
+      DistributionError::EmptyInterval => write!(f, "the interval is empty"),
+      DistributionError::NotProbability => write!(f, "a probability is not in [0, 1]"),
+      DistributionError::InvalidCount => write!(f, "a count is out of range"),
+      DistributionError::InvalidWeights => write!(f, "the weights are invalid"),
+    }
+  }
+}
+
+/// Check that all `params` are finite.
+fn finite(params: &[f64]) -> Result<(), DistributionError> {
+  if params.iter().all(|x| x.is_finite()) {
+    Ok(())
+  } else {
+    Err(DistributionError::NotFinite)
+  }
+}
+
+/// Check that all `params` are finite and positive.
+fn positive(params: &[f64]) -> Result<(), DistributionError> {
+  finite(params)?;
+  if params.iter().all(|x| *x > 0.0) {
+    Ok(())
+  } else {
+    Err(DistributionError::NotPositive)
+  }
+}
+
+/// Generate a sample of the standard normal distribution by the ziggurat method.
+fn standard_normal<R: RandomSource + ?Sized>(random: &mut R) -> f64 {
+  loop {
+    // the lowest 8 bits choose a layer, and the highest 53 bits give u (-1.0 <= u < 1.0)
+    let bits = random.bits64();
+    let i = (bits & 0xff) as usize;
+    let u = 2.0 * ((bits >> 11) as f64 * (1.0 / 9_007_199_254_740_992.0)) - 1.0;
+    let x = u * ZIG_NORM_X[i];
+    if x.abs() < ZIG_NORM_X[i + 1] {
+      return x;
+    }
+    if i == 0 {
+      // the tail beyond r
+      loop {
+        let x = -libm::log(random.open01()) / ZIG_NORM_R;
+        let y = -libm::log(random.open01());
+        if 2.0 * y >= x * x {
+          return if u < 0.0 { -ZIG_NORM_R - x } else { ZIG_NORM_R + x };
+        }
+      }
+    }
+    let f = ZIG_NORM_F[i + 1] + (ZIG_NORM_F[i] - ZIG_NORM_F[i + 1]) * random.closed_open01();
+    if f < libm::exp(-0.5 * x * x) {
+      return x;
+    }
+  }
+}
+
+/// The uniform distribution on the interval [low, high).
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Uniform {
+  low: f64,
+  high: f64,
+}
+
+impl Uniform {
+  pub fn new(low: f64, high: f64) -> Result<Uniform, DistributionError> {
+    finite(&[low, high, high - low])?;
+    if low < high {
+      Ok(Uniform { low, high })
+    } else {
+      Err(DistributionError::EmptyInterval)
+    }
+  }
+}
+
+impl Distribution<f64> for Uniform {
+  fn sample<R: RandomSource + ?Sized>(&self, random: &mut R) -> f64 {
+    loop {
+      let x = self.low + (self.high - self.low) * random.closed_open01();
+      if x < self.high {
+        return x;
+      }
+    }
+  }
+}
+
+/// The normal distribution N(mean, std_dev^2).
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Normal {
+  mean: f64,
+  std_dev: f64,
+}
+
+impl Normal {
+  pub fn new(mean: f64, std_dev: f64) -> Result<Normal, DistributionError> {
+    finite(&[mean])?;
+    positive(&[std_dev])?;
+    Ok(Normal { mean, std_dev })
+  }
+}
+
+impl Distribution<f64> for Normal {
+  fn sample<R: RandomSource + ?Sized>(&self, random: &mut R) -> f64 {
+    self.mean + self.std_dev * standard_normal(random)
+  }
+}
+
+/// The log-normal distribution, of which the logarithm follows N(mu, sigma^2).
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct LogNormal {
+  normal: Normal,
+}
+
+impl LogNormal {
+  pub fn new(mu: f64, sigma: f64) -> Result<LogNormal, DistributionError> {
+    Ok(LogNormal { normal: Normal::new(mu, sigma)? })
+  }
+}
+
+impl Distribution<f64> for LogNormal {
+  fn sample<R: RandomSource + ?Sized>(&self, random: &mut R) -> f64 {
+    libm::exp(self.normal.sample(random))
+  }
+}
+
+/// The exponential distribution with the rate `lambda`.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Exponential {
+  lambda: f64,
+}
+
+impl Exponential {
+  pub fn new(lambda: f64) -> Result<Exponential, DistributionError> {
+    positive(&[lambda])?;
+    Ok(Exponential { lambda })
+  }
+}
+
+impl Distribution<f64> for Exponential {
+  fn sample<R: RandomSource + ?Sized>(&self, random: &mut R) -> f64 {
+    -libm::log(random.open01()) / self.lambda
+  }
+}
+
+/// The gamma distribution with the shape `k` and the scale `theta`.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Gamma {
+  shape: f64,
+  scale: f64,
+}
+
+impl Gamma {
+  pub fn new(shape: f64, scale: f64) -> Result<Gamma, DistributionError> {
+    positive(&[shape, scale])?;
+    Ok(Gamma { shape, scale })
+  }
+
+  /// Generate a sample of Gamma(shape, 1) for shape >= 1 by Marsaglia and Tsang's method.
+  fn sample_large_shape<R: RandomSource + ?Sized>(shape: f64, random: &mut R) -> f64 {
+    let d = shape - 1.0 / 3.0;
+    let c = 1.0 / libm::sqrt(9.0 * d);
+    loop {
+      let x = standard_normal(random);
+      let v = 1.0 + c * x;
+      if v <= 0.0 {
+        continue;
+      }
+      let v = v * v * v;
+      let u = random.open01();
+      let x2 = x * x;
+      if u < 1.0 - 0.0331 * x2 * x2 || libm::log(u) < 0.5 * x2 + d * (1.0 - v + libm::log(v)) {
+        return d * v;
+      }
+    }
+  }
+
+  /// Generate the logarithm of a sample of Gamma(shape, 1), which doesn't underflow to `ln(0)` for
+  /// a small shape as the sample itself does.
+  fn sample_ln<R: RandomSource + ?Sized>(&self, random: &mut R) -> f64 {
+    if self.shape < 1.0 {
+      let x = Gamma::sample_large_shape(self.shape + 1.0, random);
+      libm::log(x) + libm::log(random.open01()) / self.shape
+    } else {
+      libm::log(Gamma::sample_large_shape(self.shape, random))
+    }
+  }
+}
+
+impl Distribution<f64> for Gamma {
+  fn sample<R: RandomSource + ?Sized>(&self, random: &mut R) -> f64 {
+    let x = if self.shape < 1.0 {
+      let x = Gamma::sample_large_shape(self.shape + 1.0, random);
+      x * libm::pow(random.open01(), 1.0 / self.shape)
+    } else {
+      Gamma::sample_large_shape(self.shape, random)
+    };
+    x * self.scale
+  }
+}
+
+/// The beta distribution with the shapes `alpha` and `beta`.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Beta {
+  alpha: Gamma,
+  beta: Gamma,
+}
+
+impl Beta {
+  pub fn new(alpha: f64, beta: f64) -> Result<Beta, DistributionError> {
+    Ok(Beta { alpha: Gamma::new(alpha, 1.0)?, beta: Gamma::new(beta, 1.0)? })
+  }
+}
+
+impl Distribution<f64> for Beta {
+  fn sample<R: RandomSource + ?Sized>(&self, random: &mut R) -> f64 {
+    if self.alpha.shape < 1.0 || self.beta.shape < 1.0 {
+      // both samples of Gamma can be 0 for small shapes, so X / (X + Y) is taken as
+      // 1 / (1 + exp(ln(Y) - ln(X)))
+      let ln_x = self.alpha.sample_ln(random);
+      let ln_y = self.beta.sample_ln(random);
+      1.0 / (1.0 + libm::exp(ln_y - ln_x))
+    } else {
+      let x = self.alpha.sample(random);
+      let y = self.beta.sample(random);
+      x / (x + y)
+    }
+  }
+}
+
+/// The Cauchy distribution with the location `median` and the scale `scale`.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Cauchy {
+  median: f64,
+  scale: f64,
+}
+
+impl Cauchy {
+  pub fn new(median: f64, scale: f64) -> Result<Cauchy, DistributionError> {
+    finite(&[median])?;
+    positive(&[scale])?;
+    Ok(Cauchy { median, scale })
+  }
+}
+
+impl Distribution<f64> for Cauchy {
+  fn sample<R: RandomSource + ?Sized>(&self, random: &mut R) -> f64 {
+    self.median + self.scale * libm::tan(PI * (random.open01() - 0.5))
+  }
+}
+
+/// The Weibull distribution with the scale `lambda` and the shape `k`.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Weibull {
+  scale: f64,
+  shape: f64,
+}
+
+impl Weibull {
+  pub fn new(scale: f64, shape: f64) -> Result<Weibull, DistributionError> {
+    positive(&[scale, shape])?;
+    Ok(Weibull { scale, shape })
+  }
+}
+
+impl Distribution<f64> for Weibull {
+  fn sample<R: RandomSource + ?Sized>(&self, random: &mut R) -> f64 {
+    self.scale * libm::pow(-libm::log(random.open01()), 1.0 / self.shape)
+  }
+}
@@ -0,0 +1,632 @@
+//! Discrete distributions. See the parent module for the algorithms.
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use super::{finite, positive, Distribution, DistributionError, RandomSource};
+use crate::range::below_u64;
+
+/// Generate a number r (0 <= r < n) by Lemire's method. `n` must not be 0.
+fn below<R: RandomSource + ?Sized>(random: &mut R, n: u64) -> u64 {
+  below_u64(|| random.bits64(), n)
+}
+
+/// Check that `p` is a probability.
+fn probability(p: f64) -> Result<(), DistributionError> {
+  if (0.0..=1.0).contains(&p) {
+    Ok(())
+  } else {
+    Err(DistributionError::NotProbability)
+  }
+}
+
+/// The Bernoulli distribution that gives `true` with the probability `p`. A sample takes 64 random
+/// bits and compares them with an integer threshold, so no floating point rounding is involved.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct Bernoulli {
+  /// `true` if the 64 random bits are less than this threshold.
+  threshold: u64,
+  /// `p` = 1, which the threshold can't represent.
+  always: bool,
+}
+
+impl Bernoulli {
+  /// The probability `p` is rounded down to a multiple of 2^-64.
+  pub fn new(p: f64) -> Result<Bernoulli, DistributionError> {
+    probability(p)?;
+    if p == 1.0 {
+      Ok(Bernoulli { threshold: u64::MAX, always: true })
+    } else {
+      // multiplying by 2^64 is exact, and p < 1 fits in u64
+      Ok(Bernoulli { threshold: (p * 18_446_744_073_709_551_616.0) as u64, always: false })
+    }
+  }
+
+  /// The exact probability `numerator / denominator`. A sample takes a bounded integer below
+  /// `denominator` instead of 64 random bits.
+  pub fn from_ratio(numerator: u64, denominator: u64) -> Result<BernoulliRatio, DistributionError> {
+    if denominator == 0 || numerator > denominator {
+      Err(DistributionError::InvalidCount)
+    } else {
+      Ok(BernoulliRatio { numerator, denominator })
+    }
+  }
+}
+
+impl Distribution<bool> for Bernoulli {
+  fn sample<R: RandomSource + ?Sized>(&self, random: &mut R) -> bool {
+    let bits = random.bits64();
+    self.always || bits < self.threshold
+  }
+}
+
+/// The Bernoulli distribution with an exact rational probability, created by
+/// `Bernoulli::from_ratio()`.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct BernoulliRatio {
+  numerator: u64,
+  denominator: u64,
+}
+
+impl Distribution<bool> for BernoulliRatio {
+  fn sample<R: RandomSource + ?Sized>(&self, random: &mut R) -> bool {
+    below(random, self.denominator) < self.numerator
+  }
+}
+
+/// The binomial distribution B(n, p), the number of successes in `n` trials of the probability `p`.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Binomial {
+  n: u64,
+  p: f64,
+}
+
+impl Binomial {
+  pub fn new(n: u64, p: f64) -> Result<Binomial, DistributionError> {
+    probability(p)?;
+    Ok(Binomial { n, p })
+  }
+
+  /// Inversion from the mode 0 for np < 10 and p <= 1/2, restarting at an improbably large value.
+  fn inversion<R: RandomSource + ?Sized>(n: u64, p: f64, random: &mut R) -> u64 {
+    const MAX_X: u64 = 110;
+    let q = 1.0 - p;
+    let s = p / q;
+    let a = (n as f64 + 1.0) * s;
+    let r0 = libm::pow(q, n as f64);
+    'restart: loop {
+      let mut r = r0;
+      let mut u = random.closed_open01();
+      let mut x = 0;
+      while u > r {
+        u -= r;
+        x += 1;
+        if x > MAX_X {
+          continue 'restart;
+        }
+        r *= a / x as f64 - s;
+      }
+      return x;
+    }
+  }
+
+  /// BTPE for np >= 10 and p <= 1/2.
+  fn btpe<R: RandomSource + ?Sized>(n: u64, p: f64, random: &mut R) -> u64 {
+    let nf = n as f64;
+    let q = 1.0 - p;
+    let npq = nf * p * q;
+    let f_m = nf * p + p;
+    let m = libm::floor(f_m);
+    let p1 = libm::floor(2.195 * libm::sqrt(npq) - 4.6 * q) + 0.5;
+    let x_m = m + 0.5;
+    let x_l = x_m - p1;
+    let x_r = x_m + p1;
+    let c = 0.134 + 20.5 / (15.3 + m);
+    let a = (f_m - x_l) / (f_m - x_l * p);
+    let lambda_l = a * (1.0 + 0.5 * a);
+    let a = (x_r - f_m) / (x_r * q);
+    let lambda_r = a * (1.0 + 0.5 * a);
+    let p2 = p1 * (1.0 + 2.0 * c);
+    let p3 = p2 + c / lambda_l;
+    let p4 = p3 + c / lambda_r;
+
+    loop {
+      let u = random.closed_open01() * p4;
+      let mut v = random.open01();
+      let y;
+      if u <= p1 {
+        // the triangular region, accepted immediately
+        return libm::floor(x_m - p1 * v + u) as u64;
+      } else if u <= p2 {
+        // the parallelograms
+        let x = x_l + (u - p1) / c;
+        v = v * c + 1.0 - libm::fabs(m - x + 0.5) / p1;
+        if v > 1.0 {
+          continue;
+        }
+        y = libm::floor(x);
+      } else if u <= p3 {
+        // the left exponential tail
+        y = libm::floor(x_l + libm::log(v) / lambda_l);
+        if y < 0.0 {
+          continue;
+        }
+        v *= (u - p2) * lambda_l;
+      } else {
+        // the right exponential tail
+        y = libm::floor(x_r - libm::log(v) / lambda_r);
+        if y > nf {
+          continue;
+        }
+        v *= (u - p3) * lambda_r;
+      }
+
+      let k = libm::fabs(y - m);
+      if k <= 20.0 || k >= npq / 2.0 - 1.0 {
+        // evaluate f(y) / f(m) explicitly
+        let s = p / q;
+        let a = s * (nf + 1.0);
+        let mut f = 1.0;
+        let mut i = m + 1.0;
+        while i <= y {
+          f *= a / i - s;
+          i += 1.0;
+        }
+        let mut i = y + 1.0;
+        while i <= m {
+          f /= a / i - s;
+          i += 1.0;
+        }
+        if v <= f {
+          return y as u64;
+        }
+        continue;
+      }
+
+      // squeeze with the bounds of ln(f(y) / f(m))
+      let rho = (k / npq) * ((k * (k / 3.0 + 0.625) + 1.0 / 6.0) / npq + 0.5);
+      let t = -k * k / (2.0 * npq);
+      let alpha = libm::log(v);
+      if alpha < t - rho {
+        return y as u64;
+      }
+      if alpha > t + rho {
+        continue;
+      }
+
+      // the final acceptance with Stirling's formula
+      let x1 = y + 1.0;
+      let f1 = m + 1.0;
+      let z = nf + 1.0 - m;
+      let w = nf - y + 1.0;
+      let bound = x_m * libm::log(f1 / x1)
+        + (nf - m + 0.5) * libm::log(z / w)
+        + (y - m) * libm::log(w * p / (x1 * q))
+        + stirling(f1)
+        + stirling(z)
+        - stirling(x1)
+        - stirling(w);
+      if alpha <= bound {
+        return y as u64;
+      }
+    }
+  }
+}
+
+/// The correction term of Stirling's approximation of ln(x!).
+fn stirling(a: f64) -> f64 {
+  let a2 = a * a;
+  (13860.0 - (462.0 - (132.0 - (99.0 - 140.0 / a2) / a2) / a2) / a2) / a / 166320.0
+}
+
+impl Distribution<u64> for Binomial {
+  fn sample<R: RandomSource + ?Sized>(&self, random: &mut R) -> u64 {
+    if self.p == 0.0 {
+      return 0;
+    } else if self.p == 1.0 {
+      return self.n;
+    }
+    // sample the number of failures if p > 1/2
+    let flipped = self.p > 0.5;
+    let p = if flipped { 1.0 - self.p } else { self.p };
+    let y = if (self.n as f64) * p < 10.0 {
+      Binomial::inversion(self.n, p, random)
+    } else {
+      Binomial::btpe(self.n, p, random)
+    };
+    if flipped {
+      self.n - y
+    } else {
+      y
+    }
+  }
+}
+
+/// The Poisson distribution with the mean `lambda`.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Poisson {
+  lambda: f64,
+}
+
+impl Poisson {
+  pub fn new(lambda: f64) -> Result<Poisson, DistributionError> {
+    positive(&[lambda])?;
+    Ok(Poisson { lambda })
+  }
+}
+
+impl Distribution<u64> for Poisson {
+  fn sample<R: RandomSource + ?Sized>(&self, random: &mut R) -> u64 {
+    let mu = self.lambda;
+    if mu < 10.0 {
+      // multiply uniforms until the product falls below exp(-lambda)
+      let limit = libm::exp(-mu);
+      let mut product = random.open01();
+      let mut k = 0;
+      while product > limit {
+        product *= random.open01();
+        k += 1;
+      }
+      return k;
+    }
+
+    // PTRS, the transformed rejection with squeeze
+    let smu = libm::sqrt(mu);
+    let b = 0.931 + 2.53 * smu;
+    let a = -0.059 + 0.02483 * b;
+    let inv_alpha = 1.1239 + 1.1328 / (b - 3.4);
+    let v_r = 0.9277 - 3.6224 / (b - 2.0);
+    let log_mu = libm::log(mu);
+    loop {
+      let u = random.closed_open01() - 0.5;
+      let v = random.open01();
+      let us = 0.5 - libm::fabs(u);
+      let k = libm::floor((2.0 * a / us + b) * u + mu + 0.43);
+      if us >= 0.07 && v <= v_r {
+        return k as u64;
+      }
+      if k < 0.0 || (us < 0.013 && v > us) {
+        continue;
+      }
+      let lhs = libm::log(v) + libm::log(inv_alpha) - libm::log(a / (us * us) + b);
+      if lhs <= -mu + k * log_mu - libm::lgamma(k + 1.0) {
+        return k as u64;
+      }
+    }
+  }
+}
+
+/// The geometric distribution of the number of failures before the first success of the
+/// probability `p`.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Geometric {
+  /// ln(1 - p)
+  log_q: f64,
+}
+
+impl Geometric {
+  pub fn new(p: f64) -> Result<Geometric, DistributionError> {
+    finite(&[p])?;
+    if p > 0.0 && p <= 1.0 {
+      Ok(Geometric { log_q: libm::log1p(-p) })
+    } else {
+      Err(DistributionError::NotProbability)
+    }
+  }
+}
+
+impl Distribution<u64> for Geometric {
+  fn sample<R: RandomSource + ?Sized>(&self, random: &mut R) -> u64 {
+    if self.log_q == f64::NEG_INFINITY {
+      // p = 1
+      return 0;
+    }
+    // saturates at u64::MAX for an extremely small p
+    libm::floor(libm::log(random.open01()) / self.log_q) as u64
+  }
+}
+
+/// The hypergeometric distribution of the number of successes in `draws` draws without
+/// replacement from a population of `population` items that contains `successes` successes.
+///
+/// A sample takes O(1) expected time regardless of the population. For a mode below 10, `new()`
+/// computes the probability of 0 as a product of up to about sqrt(10 population) factors.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Hypergeometric {
+  /// The number of the items of the less common kind, successes or failures.
+  n1: u64,
+  /// The number of the items of the other kind.
+  n2: u64,
+  /// The number of the items in the sampled group, the drawn items or the ones left behind,
+  /// whichever are fewer.
+  k: u64,
+  /// `n1` is the number of failures instead of successes.
+  failures: bool,
+  /// The sampled group is the items left behind instead of the drawn ones.
+  left_behind: bool,
+  draws: u64,
+  method: HypergeometricMethod,
+}
+
+/// The sampler of the number `x` of the items of `n1` in the sampled group.
+#[derive(PartialEq, Debug, Copy, Clone)]
+enum HypergeometricMethod {
+  /// HIN, inversion from 0, whose probability is `p0`.
+  Inversion { p0: f64 },
+  /// H2PE.
+  RejectionAcceptance(H2peHat),
+}
+
+/// The hat of H2PE, a uniform center [x_l, x_r) and exponential tails around the mode `m`, and the
+/// cumulative areas `p1`, `p2` and `p3` of its regions.
+#[derive(PartialEq, Debug, Copy, Clone)]
+struct H2peHat {
+  m: f64,
+  x_l: f64,
+  x_r: f64,
+  lambda_l: f64,
+  lambda_r: f64,
+  p1: f64,
+  p2: f64,
+  p3: f64,
+}
+
+impl Hypergeometric {
+  pub fn new(
+    population: u64,
+    successes: u64,
+    draws: u64,
+  ) -> Result<Hypergeometric, DistributionError> {
+    if successes > population || draws > population {
+      return Err(DistributionError::InvalidCount);
+    }
+    let n = population;
+    let failures = successes > n - successes;
+    let (n1, n2) = if failures { (n - successes, successes) } else { (successes, n - successes) };
+    let left_behind = draws > n - draws;
+    let k = if left_behind { n - draws } else { draws };
+
+    // n1 <= n / 2 <= n2 and k <= n / 2, so x can be any of 0..=min(n1, k)
+    let (n1f, n2f, kf) = (n1 as f64, n2 as f64, k as f64);
+    let m = libm::floor((kf + 1.0) * (n1f + 1.0) / (n as f64 + 2.0));
+    let method = if m < 10.0 {
+      // p0 = C(n2, k) / C(n, k) = C(n - k, n1) / C(n, n1), a product of min(n1, k) factors
+      let (count, top) = if n1 < k { (n1, n - k) } else { (k, n2) };
+      let p0 = (0..count).fold(1.0, |p, i| p * (top - i) as f64 / (n - i) as f64);
+      HypergeometricMethod::Inversion { p0 }
+    } else {
+      let variance = (n - k) as f64 * kf * n1f * n2f / ((n - 1) as f64 * n as f64 * n as f64);
+      let d = libm::floor(1.5 * libm::sqrt(variance)) + 0.5;
+      let x_l = m - d + 0.5;
+      let x_r = m + d + 0.5;
+      let k_l = libm::exp(hypergeometric_ln_ratio(n1f, n2f, kf, m, x_l));
+      let k_r = libm::exp(hypergeometric_ln_ratio(n1f, n2f, kf, m, x_r - 1.0));
+      let lambda_l = -libm::log(x_l * (n2f - kf + x_l) / ((n1f - x_l + 1.0) * (kf - x_l + 1.0)));
+      let lambda_r = -libm::log((n1f - x_r + 1.0) * (kf - x_r + 1.0) / (x_r * (n2f - kf + x_r)));
+      let p1 = 2.0 * d;
+      let p2 = p1 + k_l / lambda_l;
+      let p3 = p2 + k_r / lambda_r;
+      HypergeometricMethod::RejectionAcceptance(H2peHat {
+        m,
+        x_l,
+        x_r,
+        lambda_l,
+        lambda_r,
+        p1,
+        p2,
+        p3,
+      })
+    };
+    Ok(Hypergeometric { n1, n2, k, failures, left_behind, draws, method })
+  }
+
+  /// HIN, inversion from 0 for the mode m < 10, restarting at an improbably large value.
+  fn inversion<R: RandomSource + ?Sized>(&self, p0: f64, random: &mut R) -> u64 {
+    const MAX_X: u64 = 110;
+    let (n1, n2, k) = (self.n1 as f64, self.n2 as f64, self.k as f64);
+    'restart: loop {
+      let mut p = p0;
+      let mut u = random.closed_open01();
+      let mut x = 0;
+      while u > p {
+        u -= p;
+        // p becomes 0 past min(n1, k)
+        let xf = x as f64;
+        p *= (n1 - xf) * (k - xf) / ((xf + 1.0) * (n2 - k + xf + 1.0));
+        x += 1;
+        if x > MAX_X {
+          continue 'restart;
+        }
+      }
+      return x;
+    }
+  }
+
+  /// H2PE (Kachitvichyanukul and Schmeiser) for the mode m >= 10.
+  fn h2pe<R: RandomSource + ?Sized>(&self, hat: &H2peHat, random: &mut R) -> u64 {
+    let H2peHat { m, x_l, x_r, lambda_l, lambda_r, p1, p2, p3 } = *hat;
+    let (n1, n2, k) = (self.n1 as f64, self.n2 as f64, self.k as f64);
+    let max_y = n1.min(k);
+    loop {
+      let u = random.closed_open01() * p3;
+      let mut v = random.open01();
+      let y;
+      if u <= p1 {
+        // the uniform center
+        y = libm::floor(x_l + u);
+      } else if u <= p2 {
+        // the left exponential tail
+        y = libm::floor(x_l + libm::log(v) / lambda_l);
+        v *= (u - p1) * lambda_l;
+      } else {
+        // the right exponential tail
+        y = libm::floor(x_r - libm::log(v) / lambda_r);
+        v *= (u - p2) * lambda_r;
+      }
+      if !(0.0..=max_y).contains(&y) {
+        continue;
+      }
+
+      if m < 100.0 || y <= 50.0 {
+        // evaluate f(y) / f(m) explicitly
+        let mut f = 1.0;
+        let mut i = m + 1.0;
+        while i <= y {
+          f *= (n1 - i + 1.0) * (k - i + 1.0) / (i * (n2 - k + i));
+          i += 1.0;
+        }
+        let mut i = y + 1.0;
+        while i <= m {
+          f *= i * (n2 - k + i) / ((n1 - i + 1.0) * (k - i + 1.0));
+          i += 1.0;
+        }
+        if v <= f {
+          return y as u64;
+        }
+        continue;
+      }
+
+      // squeeze with the bounds of ln(f(y) / f(m))
+      let y1 = y + 1.0;
+      let ym = y - m;
+      let yn = n1 - y + 1.0;
+      let yk = k - y + 1.0;
+      let nk = n2 - k + y1;
+      let r = -ym / y1;
+      let s = ym / yn;
+      let t = ym / yk;
+      let e = -ym / nk;
+      let g = yn * yk / (y1 * nk) - 1.0;
+      let dg = if g < 0.0 { 1.0 + g } else { 1.0 };
+      let gu = g * (1.0 + g * (-0.5 + g / 3.0));
+      let gl = gu - g * g * g * g / (4.0 * dg);
+      let xm = m + 0.5;
+      let xn = n1 - m + 0.5;
+      let xk = k - m + 0.5;
+      let nm = n2 - k + xm;
+      let series = |x: f64| x * (1.0 + x * (-0.5 + x / 3.0));
+      let ub = xm * series(r) + xn * series(s) + xk * series(t) + nm * series(e) + y * gu - m * gl
+        + 0.0034;
+      let alpha = libm::log(v);
+      if alpha > ub {
+        continue;
+      }
+      let fourth = |a: f64, x: f64| {
+        let d = a * x * x * x * x;
+        if x < 0.0 {
+          d / (1.0 + x)
+        } else {
+          d
+        }
+      };
+      let dr = fourth(xm, r) + fourth(xn, s) + fourth(xk, t) + fourth(nm, e);
+      if alpha < ub - 0.25 * dr + (y + m) * (gl - gu) - 0.0078 {
+        return y as u64;
+      }
+
+      // the final acceptance
+      if alpha <= hypergeometric_ln_ratio(n1, n2, k, m, y) {
+        return y as u64;
+      }
+    }
+  }
+}
+
+/// ln(f(y) / f(m)) of the probability f(x) = C(n1, x) C(n2, k - x) / C(n1 + n2, k), extended to
+/// real numbers by the gamma function.
+fn hypergeometric_ln_ratio(n1: f64, n2: f64, k: f64, m: f64, y: f64) -> f64 {
+  ln_factorial_ratio(m, y)
+    + ln_factorial_ratio(n1 - m, n1 - y)
+    + ln_factorial_ratio(k - m, k - y)
+    + ln_factorial_ratio(n2 - k + m, n2 - k + y)
+}
+
+/// ln(x! / z!) by the gamma function. For large x and z, Stirling's formula is taken in the
+/// differences, since `lgamma()` loses them to cancellation.
+fn ln_factorial_ratio(x: f64, z: f64) -> f64 {
+  if x.min(z) < 30.0 || x.max(z) < 1e7 {
+    return libm::lgamma(x + 1.0) - libm::lgamma(z + 1.0);
+  }
+  let d = x - z;
+  (x + 0.5) * libm::log1p(d / (z + 1.0)) + d * libm::log(z + 1.0) - d + stirling(x + 1.0)
+    - stirling(z + 1.0)
+}
+
+impl Distribution<u64> for Hypergeometric {
+  fn sample<R: RandomSource + ?Sized>(&self, random: &mut R) -> u64 {
+    let x = match self.method {
+      HypergeometricMethod::Inversion { p0 } => self.inversion(p0, random),
+      HypergeometricMethod::RejectionAcceptance(ref hat) => self.h2pe(hat, random),
+    };
+    // x items of n1 in the sampled group
+    let drawn = if self.left_behind { self.n1 - x } else { x };
+    if self.failures {
+      self.draws - drawn
+    } else {
+      drawn
+    }
+  }
+}
+
+/// Weighted choice of an index by Walker's alias method. The table is built in O(n) by Vose's
+/// algorithm, and a sample takes a bounded integer to choose a column and 64 random bits to
+/// compare with its integer threshold.
+#[cfg(feature = "alloc")]
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct AliasTable {
+  /// The index itself is chosen if the 64 random bits are less than the threshold.
+  thresholds: Vec<u64>,
+  aliases: Vec<usize>,
+}
+
+#[cfg(feature = "alloc")]
+impl AliasTable {
+  /// Build the table for choosing index i with the probability `weights[i] / sum(weights)`.
+  pub fn new(weights: &[f64]) -> Result<AliasTable, DistributionError> {
+    let n = weights.len();
+    let sum: f64 = weights.iter().sum();
+    if n == 0
+      || weights.iter().any(|w| !w.is_finite() || *w < 0.0)
+      || !(sum > 0.0 && sum.is_finite())
+    {
+      return Err(DistributionError::InvalidWeights);
+    }
+    let mut probabilities = weights.iter().map(|w| w * n as f64 / sum).collect::<Vec<_>>();
+    let mut small = Vec::with_capacity(n);
+    let mut large = Vec::with_capacity(n);
+    for (i, p) in probabilities.iter().enumerate().rev() {
+      if *p < 1.0 {
+        small.push(i);
+      } else {
+        large.push(i);
+      }
+    }
+    let mut thresholds = vec![u64::MAX; n];
+    let mut aliases = (0..n).collect::<Vec<_>>();
+    while let (Some(&s), Some(&l)) = (small.last(), large.last()) {
+      small.pop();
+      thresholds[s] = (probabilities[s] * 18_446_744_073_709_551_616.0) as u64;
+      aliases[s] = l;
+      probabilities[l] -= 1.0 - probabilities[s];
+      if probabilities[l] < 1.0 {
+        large.pop();
+        small.push(l);
+      }
+    }
+    // the rest have the probability 1 up to rounding errors, and alias themselves
+    Ok(AliasTable { thresholds, aliases })
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl Distribution<usize> for AliasTable {
+  fn sample<R: RandomSource + ?Sized>(&self, random: &mut R) -> usize {
+    let i = below(random, self.thresholds.len() as u64) as usize;
+    if random.bits64() < self.thresholds[i] {
+      i
+    } else {
+      self.aliases[i]
+    }
+  }
+}
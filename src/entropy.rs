@@ -0,0 +1,35 @@
+//! Seeding from the entropy source of the operating system, available with the `getrandom`
+//! feature.
+//!
+//! A generator is initialized by `init_by_array()` with a 128-bit key of 16 bytes from
+//! `getrandom`, taken in little-endian as two `u64` for `TinyMT64` or four `u32` for `TinyMT32`,
+//! and the parameter set of id 0.
+use crate::{TinyMT32, TinyMT64};
+
+/// The error of the entropy source of the operating system.
+pub use getrandom::Error as EntropyError;
+
+/// Read 16 bytes from the entropy source of the operating system.
+fn os_entropy() -> Result<[u8; 16], EntropyError> {
+  let mut bytes = [0u8; 16];
+  getrandom::getrandom(&mut bytes)?;
+  Ok(bytes)
+}
+
+impl TinyMT64 {
+  /// Create a generator seeded with a 128-bit key from the operating system.
+  pub fn from_os_entropy() -> Result<TinyMT64, EntropyError> {
+    let bytes = os_entropy()?;
+    let key = [0, 8].map(|i| u64::from_le_bytes(bytes[i..i + 8].try_into().unwrap()));
+    Ok(TinyMT64::builder().seed_array(&key).build().unwrap())
+  }
+}
+
+impl TinyMT32 {
+  /// Create a generator seeded with a 128-bit key from the operating system.
+  pub fn from_os_entropy() -> Result<TinyMT32, EntropyError> {
+    let bytes = os_entropy()?;
+    let key = [0, 4, 8, 12].map(|i| u32::from_le_bytes(bytes[i..i + 4].try_into().unwrap()));
+    Ok(TinyMT32::builder().seed_array(&key).build().unwrap())
+  }
+}
@@ -0,0 +1,252 @@
+//! The `TinyMT` trait to write code once for both `TinyMT32` and `TinyMT64`.
+//!
+//! Each method is the corresponding function of `tinymt32` or `tinymt64`, so generic code gives
+//! the same outputs as code written for a concrete type.
+//!
+//! | method            | `TinyMT32`                          | `TinyMT64`                          |
+//! |-------------------|-------------------------------------|-------------------------------------|
+//! | `next_word()`     | `tinymt32_generate_uint32()`        | `tinymt64_generate_uint64()`        |
+//! | `next_f32()`      | `tinymt32_generate_float()`         | `tinymt64_generate_float()`         |
+//! | `next_f32_12()`   | `tinymt32_generate_float12()`       | `tinymt64_generate_float12()`       |
+//! | `next_f32_oc()`   | `tinymt32_generate_float_oc()`      | `tinymt64_generate_float_oc()`      |
+//! | `next_f32_oo()`   | `tinymt32_generate_float_oo()`      | `tinymt64_generate_float_oo()`      |
+//! | `next_f32_full()` | `tinymt32_generate_float_full()`    | `tinymt64_generate_float_full()`    |
+//! | `next_f64()`      | `tinymt32_generate_53double()`      | `tinymt64_generate_double()`        |
+//! | `next_f64_oc()`   | `tinymt32_generate_53double_oc()`   | `tinymt64_generate_double_oc()`     |
+//! | `next_f64_oo()`   | `tinymt32_generate_53double_oo()`   | `tinymt64_generate_double_oo()`     |
+//! | `next_f64_full()` | `tinymt32_generate_double_full()`   | `tinymt64_generate_double_full()`   |
+use core::fmt::Debug;
+
+use crate::builder::{TINYMT32_PARAMS, TINYMT64_PARAMS};
+use crate::tinymt32::*;
+use crate::tinymt64::*;
+use crate::{TinyMT32, TinyMT32Seed, TinyMT64, TinyMT64Seed};
+
+/// Common interface of `TinyMT32` and `TinyMT64`.
+///
+/// ```rust
+/// use tinymt::{TinyMT, TinyMT32, TinyMT64};
+///
+/// fn average<R: TinyMT>(random: &mut R, n: usize) -> f64 {
+///   (0..n).map(|_| random.next_f64()).sum::<f64>() / n as f64
+/// }
+///
+/// let mut random = TinyMT32::from_params(TinyMT32::DEFAULT_PARAMS);
+/// random.init(1);
+/// assert!((average(&mut random, 10000) - 0.5).abs() < 0.01);
+/// assert!((average(&mut TinyMT64::from_seed_u64(1), 10000) - 0.5).abs() < 0.01);
+/// ```
+pub trait TinyMT: Copy + Eq + Debug {
+  /// The type of an output, `u32` or `u64`.
+  type Word: Copy + Eq + Ord + Default + Debug + Into<u64>;
+
+  /// The parameter set (mat1, mat2, tmat).
+  type Params: Copy + Eq + Debug;
+
+  /// The seed of `SeedableRng`, `TinyMT32Seed` or `TinyMT64Seed`.
+  type Seed: Default + Clone + AsRef<[u8]> + AsMut<[u8]> + From<Self::Word> + Into<Self::Word>;
+
+  /// The number of bits of `Word`.
+  const WORD_BITS: u32;
+
+  /// The parameter set of id 0, which `TinyMT32::builder()` and `TinyMT64::builder()` use by
+  /// default.
+  const DEFAULT_PARAMS: Self::Params;
+
+  /// Create a generator with the parameter set `params` and the internal state of all zeros, to
+  /// be initialized by `init()` or `init_by_array()`.
+  fn from_params(params: Self::Params) -> Self;
+
+  /// The parameter set of the generator.
+  fn params(&self) -> Self::Params;
+
+  /// Initialize the internal state with the seed `seed` as `tinymt32_init()` or `tinymt64_init()`.
+  fn init(&mut self, seed: Self::Word);
+
+  /// Initialize the internal state with the array `init_key` as `tinymt32_init_by_array()` or
+  /// `tinymt64_init_by_array()`.
+  fn init_by_array(&mut self, init_key: &[Self::Word]);
+
+  /// Advance the internal state by one step as `tinymt32_next_state()` or
+  /// `tinymt64_next_state()`.
+  fn next_state(&mut self);
+
+  /// Advance the internal state by `steps` steps as `tinymt32_jump()` or `tinymt64_jump()`.
+  fn jump(&mut self, steps: u128);
+
+  /// Generate an unsigned integer r (0 <= r < 2^WORD_BITS).
+  fn next_word(&mut self) -> Self::Word;
+
+  /// Generate a floating point number r (0.0 <= r < 1.0).
+  fn next_f32(&mut self) -> f32;
+
+  /// Generate a floating point number r (1.0 <= r < 2.0).
+  fn next_f32_12(&mut self) -> f32;
+
+  /// Generate a floating point number r (0.0 < r <= 1.0).
+  fn next_f32_oc(&mut self) -> f32;
+
+  /// Generate a floating point number r (0.0 < r < 1.0).
+  fn next_f32_oo(&mut self) -> f32;
+
+  /// Generate a floating point number r (0.0 <= r < 1.0) that can be any `f32` in the interval.
+  fn next_f32_full(&mut self) -> f32;
+
+  /// Generate a double precision floating point number r (0.0 <= r < 1.0) with 53-bit precision.
+  fn next_f64(&mut self) -> f64;
+
+  /// Generate a double precision floating point number r (0.0 < r <= 1.0).
+  fn next_f64_oc(&mut self) -> f64;
+
+  /// Generate a double precision floating point number r (0.0 < r < 1.0).
+  fn next_f64_oo(&mut self) -> f64;
+
+  /// Generate a floating point number r (0.0 <= r < 1.0) that can be any `f64` in the interval.
+  fn next_f64_full(&mut self) -> f64;
+}
+
+impl TinyMT for TinyMT32 {
+  type Word = u32;
+  type Params = (u32, u32, u32);
+  type Seed = TinyMT32Seed;
+
+  const WORD_BITS: u32 = 32;
+  const DEFAULT_PARAMS: (u32, u32, u32) = TINYMT32_PARAMS[0];
+
+  fn from_params((mat1, mat2, tmat): (u32, u32, u32)) -> Self {
+    TinyMT32::new([0, 0, 0, 0], mat1, mat2, tmat)
+  }
+
+  fn params(&self) -> (u32, u32, u32) {
+    (self.mat1, self.mat2, self.tmat)
+  }
+
+  fn init(&mut self, seed: u32) {
+    tinymt32_init(self, seed)
+  }
+
+  fn init_by_array(&mut self, init_key: &[u32]) {
+    tinymt32_init_by_array(self, init_key)
+  }
+
+  fn next_state(&mut self) {
+    tinymt32_next_state(self)
+  }
+
+  fn jump(&mut self, steps: u128) {
+    tinymt32_jump(self, steps)
+  }
+
+  fn next_word(&mut self) -> u32 {
+    tinymt32_generate_uint32(self)
+  }
+
+  fn next_f32(&mut self) -> f32 {
+    tinymt32_generate_float(self)
+  }
+
+  fn next_f32_12(&mut self) -> f32 {
+    tinymt32_generate_float12(self)
+  }
+
+  fn next_f32_oc(&mut self) -> f32 {
+    tinymt32_generate_float_oc(self)
+  }
+
+  fn next_f32_oo(&mut self) -> f32 {
+    tinymt32_generate_float_oo(self)
+  }
+
+  fn next_f32_full(&mut self) -> f32 {
+    tinymt32_generate_float_full(self)
+  }
+
+  fn next_f64(&mut self) -> f64 {
+    tinymt32_generate_53double(self)
+  }
+
+  fn next_f64_oc(&mut self) -> f64 {
+    tinymt32_generate_53double_oc(self)
+  }
+
+  fn next_f64_oo(&mut self) -> f64 {
+    tinymt32_generate_53double_oo(self)
+  }
+
+  fn next_f64_full(&mut self) -> f64 {
+    tinymt32_generate_double_full(self)
+  }
+}
+
+impl TinyMT for TinyMT64 {
+  type Word = u64;
+  type Params = (u32, u32, u64);
+  type Seed = TinyMT64Seed;
+
+  const WORD_BITS: u32 = 64;
+  const DEFAULT_PARAMS: (u32, u32, u64) = TINYMT64_PARAMS[0];
+
+  fn from_params((mat1, mat2, tmat): (u32, u32, u64)) -> Self {
+    TinyMT64::new([0, 0], mat1, mat2, tmat)
+  }
+
+  fn params(&self) -> (u32, u32, u64) {
+    (self.mat1, self.mat2, self.tmat)
+  }
+
+  fn init(&mut self, seed: u64) {
+    tinymt64_init(self, seed)
+  }
+
+  fn init_by_array(&mut self, init_key: &[u64]) {
+    tinymt64_init_by_array(self, init_key)
+  }
+
+  fn next_state(&mut self) {
+    tinymt64_next_state(self)
+  }
+
+  fn jump(&mut self, steps: u128) {
+    tinymt64_jump(self, steps)
+  }
+
+  fn next_word(&mut self) -> u64 {
+    tinymt64_generate_uint64(self)
+  }
+
+  fn next_f32(&mut self) -> f32 {
+    tinymt64_generate_float(self)
+  }
+
+  fn next_f32_12(&mut self) -> f32 {
+    tinymt64_generate_float12(self)
+  }
+
+  fn next_f32_oc(&mut self) -> f32 {
+    tinymt64_generate_float_oc(self)
+  }
+
+  fn next_f32_oo(&mut self) -> f32 {
+    tinymt64_generate_float_oo(self)
+  }
+
+  fn next_f32_full(&mut self) -> f32 {
+    tinymt64_generate_float_full(self)
+  }
+
+  fn next_f64(&mut self) -> f64 {
+    tinymt64_generate_double(self)
+  }
+
+  fn next_f64_oc(&mut self) -> f64 {
+    tinymt64_generate_double_oc(self)
+  }
+
+  fn next_f64_oo(&mut self) -> f64 {
+    tinymt64_generate_double_oo(self)
+  }
+
+  fn next_f64_full(&mut self) -> f64 {
+    tinymt64_generate_double_full(self)
+  }
+}
@@ -0,0 +1,94 @@
+/// Linear transformation over GF(2) on a state vector of up to 128 bits, such as the 127-bit
+/// internal state of TinyMT. The matrix is stored column by column; `columns[j]` is the image of
+/// the state that has only the j-th bit set.
+#[derive(PartialEq, Eq, Clone)]
+pub(crate) struct Matrix {
+  columns: [u128; 128],
+}
+
+impl Matrix {
+  /// Build the matrix of the linear map `f` by applying it to each unit vector.
+  pub fn from_fn<F: Fn(u128) -> u128>(f: F) -> Matrix {
+    let mut columns = [0u128; 128];
+    for (j, column) in columns.iter_mut().enumerate() {
+      *column = f(1u128 << j);
+    }
+    Matrix { columns }
+  }
+
+  /// Identity on the bits set in `mask` and zero elsewhere.
+  pub fn identity(mask: u128) -> Matrix {
+    Matrix::from_fn(|v| v & mask)
+  }
+
+  /// Transform the vector `v` by this matrix.
+  pub fn apply(&self, mut v: u128) -> u128 {
+    let mut result = 0u128;
+    while v != 0 {
+      let j = v.trailing_zeros() as usize;
+      result ^= self.columns[j];
+      v &= v - 1;
+    }
+    result
+  }
+
+  /// The composite transformation that applies `other` and then this matrix.
+  pub fn mul(&self, other: &Matrix) -> Matrix {
+    let mut columns = [0u128; 128];
+    for (column, v) in columns.iter_mut().zip(other.columns.iter()) {
+      *column = self.apply(*v);
+    }
+    Matrix { columns }
+  }
+
+  /// This matrix to the power of `n`, in O(log n) matrix products.
+  pub fn pow(&self, mut n: u128) -> Matrix {
+    let mut result = Matrix::identity(u128::MAX);
+    let mut m = self.clone();
+    while n != 0 {
+      if n & 1 != 0 {
+        result = m.mul(&result);
+      }
+      n >>= 1;
+      if n != 0 {
+        m = m.mul(&m);
+      }
+    }
+    result
+  }
+
+  /// Transform the vector `v` by this matrix `n` times, in O(log n) matrix products.
+  pub fn apply_pow(&self, mut v: u128, mut n: u128) -> u128 {
+    let mut m = self.clone();
+    while n != 0 {
+      if n & 1 != 0 {
+        v = m.apply(v);
+      }
+      n >>= 1;
+      if n != 0 {
+        m = m.mul(&m);
+      }
+    }
+    v
+  }
+
+  /// Determine whether this matrix, operating on the 127 bits set in `mask`, has the multiplicative
+  /// order 2^127-1. Since 2^127-1 is a Mersenne prime, this holds iff M^(2^127-1) = I and M ≠ I,
+  /// and then the characteristic polynomial is primitive and every non-zero state has the period
+  /// 2^127-1.
+  pub fn has_maximal_order(&self, mask: u128) -> bool {
+    debug_assert_eq!(127, mask.count_ones());
+    let identity = Matrix::identity(mask);
+    if *self == identity {
+      return false;
+    }
+    // M^(2^127-1) = M^(2^0) M^(2^1) ... M^(2^126)
+    let mut square = self.clone();
+    let mut product = self.clone();
+    for _ in 1..127 {
+      square = square.mul(&square);
+      product = product.mul(&square);
+    }
+    product == identity
+  }
+}
@@ -0,0 +1,122 @@
+//! Infinite iterators over the outputs of the generate functions.
+//!
+//! The iterators borrow the generator mutably, so the generator continues after the last output
+//! taken from the iterator once it's dropped, and `by_ref()` or `take()` leave the rest of the
+//! stream to the following code.
+use core::iter::FusedIterator;
+
+use crate::tinymt32::*;
+use crate::tinymt64::*;
+use crate::{TinyMT32, TinyMT64};
+
+/// Endless iterator over the outputs of a generate function of `TinyMT32` or `TinyMT64`, created
+/// by `iter_u32()`, `iter_f32()`, `iter_u64()`, `iter_f64()` and their interval variants. Each
+/// `next()` calls the generate function once and never returns `None`.
+pub struct Outputs<'a, R, T> {
+  random: &'a mut R,
+  generate: fn(&mut R) -> T,
+}
+
+impl<'a, R, T> Iterator for Outputs<'a, R, T> {
+  type Item = T;
+
+  #[inline]
+  fn next(&mut self) -> Option<T> {
+    Some((self.generate)(self.random))
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (usize::MAX, None)
+  }
+}
+
+impl<'a, R, T> FusedIterator for Outputs<'a, R, T> {}
+
+impl TinyMT32 {
+  /// The outputs of `tinymt32_generate_uint32()`: 0 <= r < 2^32.
+  pub fn iter_u32(&mut self) -> Outputs<'_, TinyMT32, u32> {
+    Outputs { random: self, generate: tinymt32_generate_uint32 }
+  }
+
+  /// The outputs of `tinymt32_generate_float()`: 0.0 <= r < 1.0.
+  pub fn iter_f32(&mut self) -> Outputs<'_, TinyMT32, f32> {
+    Outputs { random: self, generate: tinymt32_generate_float }
+  }
+
+  /// The outputs of `tinymt32_generate_float12()`: 1.0 <= r < 2.0.
+  pub fn iter_f32_12(&mut self) -> Outputs<'_, TinyMT32, f32> {
+    Outputs { random: self, generate: tinymt32_generate_float12 }
+  }
+
+  /// The outputs of `tinymt32_generate_float_oc()`: 0.0 < r <= 1.0.
+  pub fn iter_f32_oc(&mut self) -> Outputs<'_, TinyMT32, f32> {
+    Outputs { random: self, generate: tinymt32_generate_float_oc }
+  }
+
+  /// The outputs of `tinymt32_generate_float_oo()`: 0.0 < r < 1.0.
+  pub fn iter_f32_oo(&mut self) -> Outputs<'_, TinyMT32, f32> {
+    Outputs { random: self, generate: tinymt32_generate_float_oo }
+  }
+
+  /// The outputs of `tinymt32_generate_53double()`: 0.0 <= r < 1.0.
+  pub fn iter_f64(&mut self) -> Outputs<'_, TinyMT32, f64> {
+    Outputs { random: self, generate: tinymt32_generate_53double }
+  }
+
+  /// The outputs of `tinymt32_generate_53double_oc()`: 0.0 < r <= 1.0.
+  pub fn iter_f64_oc(&mut self) -> Outputs<'_, TinyMT32, f64> {
+    Outputs { random: self, generate: tinymt32_generate_53double_oc }
+  }
+
+  /// The outputs of `tinymt32_generate_53double_oo()`: 0.0 < r < 1.0.
+  pub fn iter_f64_oo(&mut self) -> Outputs<'_, TinyMT32, f64> {
+    Outputs { random: self, generate: tinymt32_generate_53double_oo }
+  }
+}
+
+impl TinyMT64 {
+  /// The outputs of `tinymt64_generate_uint64()`: 0 <= r < 2^64.
+  pub fn iter_u64(&mut self) -> Outputs<'_, TinyMT64, u64> {
+    Outputs { random: self, generate: tinymt64_generate_uint64 }
+  }
+
+  /// The outputs of `tinymt64_generate_double()`: 0.0 <= r < 1.0.
+  pub fn iter_f64(&mut self) -> Outputs<'_, TinyMT64, f64> {
+    Outputs { random: self, generate: tinymt64_generate_double }
+  }
+
+  /// The outputs of `tinymt64_generate_double12()`: 1.0 <= r < 2.0.
+  pub fn iter_f64_12(&mut self) -> Outputs<'_, TinyMT64, f64> {
+    Outputs { random: self, generate: tinymt64_generate_double12 }
+  }
+
+  /// The outputs of `tinymt64_generate_double_oc()`: 0.0 < r <= 1.0.
+  pub fn iter_f64_oc(&mut self) -> Outputs<'_, TinyMT64, f64> {
+    Outputs { random: self, generate: tinymt64_generate_double_oc }
+  }
+
+  /// The outputs of `tinymt64_generate_double_oo()`: 0.0 < r < 1.0.
+  pub fn iter_f64_oo(&mut self) -> Outputs<'_, TinyMT64, f64> {
+    Outputs { random: self, generate: tinymt64_generate_double_oo }
+  }
+
+  /// The outputs of `tinymt64_generate_float()`: 0.0 <= r < 1.0.
+  pub fn iter_f32(&mut self) -> Outputs<'_, TinyMT64, f32> {
+    Outputs { random: self, generate: tinymt64_generate_float }
+  }
+
+  /// The outputs of `tinymt64_generate_float12()`: 1.0 <= r < 2.0.
+  pub fn iter_f32_12(&mut self) -> Outputs<'_, TinyMT64, f32> {
+    Outputs { random: self, generate: tinymt64_generate_float12 }
+  }
+
+  /// The outputs of `tinymt64_generate_float_oc()`: 0.0 < r <= 1.0.
+  pub fn iter_f32_oc(&mut self) -> Outputs<'_, TinyMT64, f32> {
+    Outputs { random: self, generate: tinymt64_generate_float_oc }
+  }
+
+  /// The outputs of `tinymt64_generate_float_oo()`: 0.0 < r < 1.0.
+  pub fn iter_f32_oo(&mut self) -> Outputs<'_, TinyMT64, f32> {
+    Outputs { random: self, generate: tinymt64_generate_float_oo }
+  }
+}
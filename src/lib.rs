@@ -1,5 +1,6 @@
 //!
 //! ```rust
+//! # #[cfg(feature = "rand")] {
 //! use tinymt::{TinyMT64, TinyMT64Seed, TinyMT32};
 //! use rand::{Rng, SeedableRng};
 //!
@@ -12,19 +13,101 @@
 //! let mut random = TinyMT64::from_seed(TinyMT64Seed::from(0u64));
 //! let rn = random.gen_range(0.0..1.0);
 //! assert!((0.0..1.0).contains(&rn));
+//! # }
 //! ```
 //!
-//! This crate is `no_std` compatible.
+//! This crate is `no_std` compatible. The constructors and the low-level initialization and
+//! generation functions are `const fn`, so tables of random numbers can be computed at compile
+//! time. The default `alloc` feature enables the items that need an allocator, such as
+//! `distributions::AliasTable`. The default `distributions` feature enables the `distributions`
+//! module and its dependency on `libm`; without the default features, only the generators are
+//! compiled.
+//!
+//! The `rand_core_06` and `rand_core_09` features implement `RngCore` and `SeedableRng` of
+//! `rand_core` 0.6 and 0.9, which `rand` 0.8 and 0.9 are built on, and can be enabled together. The
+//! default `rand` feature is an alias of `rand_core_06`. Without them, the generators have no
+//! dependency on the `rand` family and provide the same `next_u32()`, `next_u64()` and
+//! `fill_bytes()` as inherent methods.
+//!
+//! The `std` feature adds `thread_rng64()` and `thread_rng32()`, handles to generators local to
+//! the current thread. Setting the environment variable `TINYMT_SEED` makes them deterministic.
+//! It also adds `RandomReader`, which reads the outputs of a generator as `std::io::Read`.
+//!
+//! The `getrandom` feature adds `TinyMT64::from_os_entropy()` and `TinyMT32::from_os_entropy()`,
+//! which seed a generator with a 128-bit key from the operating system and return an error instead
+//! of panicking if it's unavailable.
+//!
+//! The `critical-section` feature adds `SharedTinyMT32`, which can be shared between the main loop
+//! and interrupt handlers on bare-metal targets.
+//!
+//! The `sync` feature adds the `sync` module with `AtomicTinyMT64` and `ShardedTinyMT64`, which
+//! many threads can draw from without a `Mutex`.
+//!
+//! The `rayon` feature adds `par_fill_u64()` and `ParallelTinyMT64`, which generate in parallel
+//! with the same results regardless of the number of threads.
+//!
+//! The `TinyMT` trait is implemented by both generators, with the word type `u32` or `u64` as an
+//! associated type, so utilities can be written once for both.
+//!
+//! ```rust
+//! use tinymt::tinymt64::tinymt64_const_array;
+//!
+//! static ZOBRIST_KEYS: [u64; 768] =
+//!   tinymt64_const_array(0xfa051f40, 0xffd0fff4, 0x58d02ffeffbfffbc, 42);
+//! assert_ne!(ZOBRIST_KEYS[0], ZOBRIST_KEYS[1]);
+//! ```
 //!
 #![no_std]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 use core::cmp::min;
 
-use rand::{Error, RngCore, SeedableRng};
+pub use builder::{BuildError, TinyMT32Builder, TinyMT64Builder};
+#[cfg(feature = "getrandom")]
+pub use entropy::EntropyError;
+pub use generic::TinyMT;
+pub use iter::Outputs;
+#[cfg(feature = "rayon")]
+pub use parallel::{par_fill_u64, ParallelTinyMT64};
+#[cfg(feature = "rand_core_06")]
+pub use rand_core_impls::{BlockTinyMT32, BlockTinyMT64};
+#[cfg(feature = "std")]
+pub use reader::{Endian, RandomReader};
+pub use seq::SampleIndices;
+#[cfg(feature = "critical-section")]
+pub use shared::SharedTinyMT32;
+#[cfg(feature = "std")]
+pub use thread::{thread_rng32, thread_rng64, ThreadTinyMT32, ThreadTinyMT64};
 
+mod builder;
+#[cfg(feature = "distributions")]
+pub mod distributions;
+#[cfg(feature = "getrandom")]
+mod entropy;
+mod generic;
+mod gf2;
+mod iter;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(any(feature = "rand_core_06", feature = "rand_core_09"))]
+mod rand_core_impls;
+mod range;
+#[cfg(feature = "std")]
+mod reader;
+mod seq;
+#[cfg(feature = "critical-section")]
+mod shared;
+#[cfg(feature = "sync")]
+pub mod sync;
+#[cfg(feature = "std")]
+mod thread;
 pub mod tinymt32;
 pub mod tinymt64;
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct TinyMT64Seed(pub [u8; 8]);
 
 impl From<u64> for TinyMT64Seed {
@@ -39,6 +122,12 @@ impl From<TinyMT64Seed> for u64 {
   }
 }
 
+impl AsRef<[u8]> for TinyMT64Seed {
+  fn as_ref(&self) -> &[u8] {
+    &self.0
+  }
+}
+
 impl AsMut<[u8]> for TinyMT64Seed {
   fn as_mut(&mut self) -> &mut [u8] {
     &mut self.0
@@ -46,6 +135,9 @@ impl AsMut<[u8]> for TinyMT64Seed {
 }
 
 /// random TinyMT state vector
+///
+/// The memory layout is the same as `tinymt64_t` of the original C implementation.
+#[repr(C)]
 #[derive(PartialEq, Eq, Debug, Copy, Clone)]
 pub struct TinyMT64 {
   status: [u64; 2],
@@ -55,31 +147,42 @@ pub struct TinyMT64 {
 }
 
 impl TinyMT64 {
-  pub fn from_seed_u64(seed: u64) -> Self {
-    Self::from_seed(TinyMT64Seed::from(seed))
+  pub const fn from_seed_u64(seed: u64) -> Self {
+    let mut random = TinyMT64 { status: [0, 0], mat1: 0, mat2: 0, tmat: 0 };
+    tinymt64::tinymt64_init(&mut random, seed);
+    random
   }
-}
 
-impl SeedableRng for TinyMT64 {
-  type Seed = TinyMT64Seed;
+  /// Generate a single precision floating point number r (0.0 <= r < 1.0) from the upper 24 bits
+  /// of a 64-bit output, as `tinymt64_generate_float()`.
+  pub fn next_f32(&mut self) -> f32 {
+    tinymt64::tinymt64_generate_float(self)
+  }
 
-  fn from_seed(seed: Self::Seed) -> Self {
-    let mut random = TinyMT64 { status: [0, 0], mat1: 0, mat2: 0, tmat: 0 };
-    tinymt64::tinymt64_init(&mut random, u64::from(seed));
-    random
+  /// Generate a floating point number r (0.0 <= r < 1.0) that can be any `f32` in the interval,
+  /// as `tinymt64_generate_float_full()`.
+  pub fn next_f32_full(&mut self) -> f32 {
+    tinymt64::tinymt64_generate_float_full(self)
   }
-}
 
-impl RngCore for TinyMT64 {
-  fn next_u32(&mut self) -> u32 {
+  /// Generate a floating point number r (0.0 <= r < 1.0) that can be any `f64` in the interval,
+  /// as `tinymt64_generate_double_full()`.
+  pub fn next_f64_full(&mut self) -> f64 {
+    tinymt64::tinymt64_generate_double_full(self)
+  }
+
+  /// Generate a 32-bit unsigned integer. This is the same as `RngCore::next_u32()`.
+  pub fn next_u32(&mut self) -> u32 {
     self.next_u64() as u32
   }
 
-  fn next_u64(&mut self) -> u64 {
+  /// Generate a 64-bit unsigned integer. This is the same as `RngCore::next_u64()`.
+  pub fn next_u64(&mut self) -> u64 {
     tinymt64::tinymt64_generate_uint64(self)
   }
 
-  fn fill_bytes(&mut self, dest: &mut [u8]) {
+  /// Fill `dest` with random bytes. This is the same as `RngCore::fill_bytes()`.
+  pub fn fill_bytes(&mut self, dest: &mut [u8]) {
     let mut position = 0;
     let mut remaining = dest.len();
     while remaining > 0 {
@@ -91,14 +194,9 @@ impl RngCore for TinyMT64 {
       }
     }
   }
-
-  fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-    self.fill_bytes(dest);
-    Ok(())
-  }
 }
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct TinyMT32Seed(pub [u8; 4]);
 
 impl From<u32> for TinyMT32Seed {
@@ -113,6 +211,12 @@ impl From<TinyMT32Seed> for u32 {
   }
 }
 
+impl AsRef<[u8]> for TinyMT32Seed {
+  fn as_ref(&self) -> &[u8] {
+    &self.0
+  }
+}
+
 impl AsMut<[u8]> for TinyMT32Seed {
   fn as_mut(&mut self) -> &mut [u8] {
     &mut self.0
@@ -120,6 +224,9 @@ impl AsMut<[u8]> for TinyMT32Seed {
 }
 
 /// tinymt32 internal state vector and parameters
+///
+/// The memory layout is the same as `tinymt32_t` of the original C implementation.
+#[repr(C)]
 #[derive(PartialEq, Eq, Debug, Copy, Clone)]
 pub struct TinyMT32 {
   status: [u32; 4],
@@ -129,31 +236,42 @@ pub struct TinyMT32 {
 }
 
 impl TinyMT32 {
-  pub fn from_seed_u32(seed: u32) -> Self {
-    Self::from_seed(TinyMT32Seed::from(seed))
+  pub const fn from_seed_u32(seed: u32) -> Self {
+    let mut random = TinyMT32 { status: [0, 0, 0, 0], mat1: 0, mat2: 0, tmat: 0 };
+    tinymt32::tinymt32_init(&mut random, seed);
+    random
   }
-}
 
-impl SeedableRng for TinyMT32 {
-  type Seed = TinyMT32Seed;
+  /// Generate a double precision floating point number r (0.0 <= r < 1.0) with 53-bit precision
+  /// from two 32-bit outputs, as `tinymt32_generate_53double()`.
+  pub fn next_f64(&mut self) -> f64 {
+    tinymt32::tinymt32_generate_53double(self)
+  }
 
-  fn from_seed(seed: Self::Seed) -> Self {
-    let mut random = TinyMT32 { status: [0, 0, 0, 0], mat1: 0, mat2: 0, tmat: 0 };
-    tinymt32::tinymt32_init(&mut random, u32::from(seed));
-    random
+  /// Generate a floating point number r (0.0 <= r < 1.0) that can be any `f32` in the interval,
+  /// as `tinymt32_generate_float_full()`.
+  pub fn next_f32_full(&mut self) -> f32 {
+    tinymt32::tinymt32_generate_float_full(self)
+  }
+
+  /// Generate a floating point number r (0.0 <= r < 1.0) that can be any `f64` in the interval,
+  /// as `tinymt32_generate_double_full()`.
+  pub fn next_f64_full(&mut self) -> f64 {
+    tinymt32::tinymt32_generate_double_full(self)
   }
-}
 
-impl RngCore for TinyMT32 {
-  fn next_u32(&mut self) -> u32 {
+  /// Generate a 32-bit unsigned integer. This is the same as `RngCore::next_u32()`.
+  pub fn next_u32(&mut self) -> u32 {
     tinymt32::tinymt32_generate_uint32(self)
   }
 
-  fn next_u64(&mut self) -> u64 {
+  /// Generate a 64-bit unsigned integer. This is the same as `RngCore::next_u64()`.
+  pub fn next_u64(&mut self) -> u64 {
     ((self.next_u32() as u64) << 32) | (self.next_u32() as u64)
   }
 
-  fn fill_bytes(&mut self, dest: &mut [u8]) {
+  /// Fill `dest` with random bytes. This is the same as `RngCore::fill_bytes()`.
+  pub fn fill_bytes(&mut self, dest: &mut [u8]) {
     let mut position = 0;
     let mut remaining = dest.len();
     while remaining > 0 {
@@ -165,9 +283,4 @@ impl RngCore for TinyMT32 {
       }
     }
   }
-
-  fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-    self.fill_bytes(dest);
-    Ok(())
-  }
 }
@@ -0,0 +1,108 @@
+//! Deterministic parallel generation with rayon, available with the `rayon` feature.
+//!
+//! The slice is split into chunks, and the chunk i is generated from the substream that starts
+//! i × stride outputs ahead of the base generator. The starting states are computed by the jump
+//! matrix before the chunks are handed to rayon, so the results depend only on the base generator
+//! and the chunk length, not on the number of threads or the scheduling.
+use alloc::vec::Vec;
+
+use rayon::prelude::*;
+
+use crate::tinymt64::{tinymt64_generate_uint64, tinymt64_jump_states, tinymt64_next_state};
+use crate::TinyMT64;
+
+/// Fill `dest` in parallel with the same values as filling it sequentially by `next_u64()` of
+/// `TinyMT64::builder().seed(seed).build()`.
+///
+/// ```rust
+/// use tinymt::{par_fill_u64, TinyMT64};
+///
+/// let mut values = vec![0u64; 100_000];
+/// par_fill_u64(&mut values, 42);
+/// let mut random = TinyMT64::builder().seed(42).build().unwrap();
+/// assert!(values.iter().all(|x| *x == random.next_u64()));
+/// ```
+pub fn par_fill_u64(dest: &mut [u64], seed: u64) {
+  let random = TinyMT64::builder().seed(seed).build().unwrap();
+  ParallelTinyMT64::new(random).fill_u64(dest);
+}
+
+/// Adapter of `TinyMT64` to generate in parallel with rayon, with results that don't depend on
+/// the number of threads.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct ParallelTinyMT64 {
+  random: TinyMT64,
+  chunk_len: usize,
+}
+
+impl ParallelTinyMT64 {
+  /// The number of elements of a chunk by default.
+  pub const DEFAULT_CHUNK_LEN: usize = 1 << 16;
+
+  /// The distance between the substreams of two adjacent chunks of `for_each_chunk()`.
+  pub const CHUNK_STRIDE: u128 = 1 << 64;
+
+  /// Generate from `random` in chunks of `DEFAULT_CHUNK_LEN` elements.
+  pub fn new(random: TinyMT64) -> Self {
+    Self::with_chunk_len(random, Self::DEFAULT_CHUNK_LEN)
+  }
+
+  /// Generate from `random` in chunks of `chunk_len` elements.
+  ///
+  /// # Panics
+  /// If `chunk_len` is 0.
+  pub fn with_chunk_len(random: TinyMT64, chunk_len: usize) -> Self {
+    assert!(chunk_len != 0, "the chunk length must not be 0");
+    ParallelTinyMT64 { random, chunk_len }
+  }
+
+  /// The base generator, which has advanced past the outputs of the fills so far.
+  pub fn random(&self) -> &TinyMT64 {
+    &self.random
+  }
+
+  /// Fill `dest` in parallel with the same values as filling it sequentially by `next_u64()` of
+  /// the base generator, and advance the base generator past them. The chunk i starts
+  /// i × `chunk_len` outputs ahead, so consecutive chunks continue the stream.
+  pub fn fill_u64(&mut self, dest: &mut [u64]) {
+    if dest.is_empty() {
+      return;
+    }
+    let states = tinymt64_jump_states(&self.random, self.chunk_len as u128)
+      .take(dest.len().div_ceil(self.chunk_len))
+      .collect::<Vec<_>>();
+    dest.par_chunks_mut(self.chunk_len).zip(states.par_iter()).for_each(|(chunk, random)| {
+      let mut random = *random;
+      for value in chunk.iter_mut() {
+        *value = tinymt64_generate_uint64(&mut random);
+      }
+    });
+    // the last chunk starts within chunk_len steps of the end
+    let mut random = *states.last().unwrap();
+    for _ in 0..dest.len() - (states.len() - 1) * self.chunk_len {
+      tinymt64_next_state(&mut random);
+    }
+    self.random = random;
+  }
+
+  /// Call `f` in parallel for each chunk of `dest` with a generator for the chunk. The chunk i is
+  /// given the substream that starts i × `CHUNK_STRIDE` outputs ahead of the base generator, so
+  /// `f` may take any number of outputs less than `CHUNK_STRIDE` for a chunk. The base generator
+  /// doesn't advance.
+  pub fn for_each_chunk<T, F>(&self, dest: &mut [T], f: F)
+  where
+    T: Send,
+    F: Fn(&mut TinyMT64, &mut [T]) + Sync + Send,
+  {
+    if dest.is_empty() {
+      return;
+    }
+    let states = tinymt64_jump_states(&self.random, Self::CHUNK_STRIDE)
+      .take(dest.len().div_ceil(self.chunk_len))
+      .collect::<Vec<_>>();
+    dest.par_chunks_mut(self.chunk_len).zip(states.par_iter()).for_each(|(chunk, random)| {
+      let mut random = *random;
+      f(&mut random, chunk)
+    });
+  }
+}
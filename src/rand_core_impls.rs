@@ -0,0 +1,205 @@
+//! Implementations of the traits of `rand_core` 0.6 and 0.9. Both generations delegate to the
+//! inherent methods, so they give the same streams, and can be enabled at the same time.
+//!
+//! `BlockRngCore` generates `BLOCK_WORDS_32` or `BLOCK_WORDS_64` outputs at once on a copy of the
+//! state. The words of the block are the same as those of `next_u32()` of `TinyMT32` and
+//! `next_u64()` of `TinyMT64`.
+use crate::tinymt32::tinymt32_generate_uint32;
+use crate::tinymt64::tinymt64_generate_uint64;
+use crate::{TinyMT32, TinyMT32Seed, TinyMT64, TinyMT64Seed};
+
+/// The number of 32-bit words in a block of `TinyMT32`.
+const BLOCK_WORDS_32: usize = 32;
+
+/// The number of 64-bit words in a block of `TinyMT64`.
+const BLOCK_WORDS_64: usize = 16;
+
+/// Fill a block with the outputs of `generate` on a local copy of the state.
+macro_rules! generate_block {
+  ($random:expr, $results:expr, $generate:ident) => {{
+    let mut random = *$random;
+    for word in $results.iter_mut() {
+      *word = $generate(&mut random);
+    }
+    *$random = random;
+  }};
+}
+
+macro_rules! impl_rand_core {
+  ($rand_core:ident, $t:ty, $seed:ty, $from_seed:ident, $word:ty $(, $try_fill_bytes:item)?) => {
+    impl $rand_core::SeedableRng for $t {
+      type Seed = $seed;
+
+      fn from_seed(seed: Self::Seed) -> Self {
+        Self::$from_seed(<$word>::from(seed))
+      }
+    }
+
+    impl $rand_core::RngCore for $t {
+      fn next_u32(&mut self) -> u32 {
+        <$t>::next_u32(self)
+      }
+
+      fn next_u64(&mut self) -> u64 {
+        <$t>::next_u64(self)
+      }
+
+      fn fill_bytes(&mut self, dest: &mut [u8]) {
+        <$t>::fill_bytes(self, dest)
+      }
+
+      $($try_fill_bytes)?
+    }
+  };
+}
+
+macro_rules! impl_block_rng_core {
+  ($rand_core:ident) => {
+    impl $rand_core::block::BlockRngCore for TinyMT32 {
+      type Item = u32;
+      type Results = [u32; BLOCK_WORDS_32];
+
+      fn generate(&mut self, results: &mut Self::Results) {
+        generate_block!(self, results, tinymt32_generate_uint32)
+      }
+    }
+
+    impl $rand_core::block::BlockRngCore for TinyMT64 {
+      type Item = u64;
+      type Results = [u64; BLOCK_WORDS_64];
+
+      fn generate(&mut self, results: &mut Self::Results) {
+        generate_block!(self, results, tinymt64_generate_uint64)
+      }
+    }
+  };
+}
+
+#[cfg(feature = "rand_core_06")]
+mod v06 {
+  use super::*;
+  use rand_core_06::block::{BlockRng, BlockRng64};
+  use rand_core_06::{Error, RngCore, SeedableRng};
+
+  /// `TinyMT32` buffered by `BlockRng` of `rand_core` 0.6. `next_u32()`, `next_u64()` and
+  /// `fill_bytes()` give the same stream as `TinyMT32`, in any mix of the calls.
+  #[derive(Debug, Clone)]
+  pub struct BlockTinyMT32 {
+    block: BlockRng<TinyMT32>,
+  }
+
+  impl BlockTinyMT32 {
+    /// Buffer the outputs of `random`.
+    pub fn new(random: TinyMT32) -> Self {
+      BlockTinyMT32 { block: BlockRng::new(random) }
+    }
+  }
+
+  impl SeedableRng for BlockTinyMT32 {
+    type Seed = TinyMT32Seed;
+
+    fn from_seed(seed: Self::Seed) -> Self {
+      Self::new(TinyMT32::from_seed(seed))
+    }
+  }
+
+  impl RngCore for BlockTinyMT32 {
+    fn next_u32(&mut self) -> u32 {
+      self.block.next_u32()
+    }
+
+    /// Combine two words as `(first << 32) | second`, as `TinyMT32::next_u64()`.
+    fn next_u64(&mut self) -> u64 {
+      ((self.block.next_u32() as u64) << 32) | (self.block.next_u32() as u64)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+      self.block.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+      self.fill_bytes(dest);
+      Ok(())
+    }
+  }
+
+  /// `TinyMT64` buffered by `BlockRng64` of `rand_core` 0.6. `next_u32()`, `next_u64()` and
+  /// `fill_bytes()` give the same stream as `TinyMT64`, in any mix of the calls.
+  #[derive(Debug, Clone)]
+  pub struct BlockTinyMT64 {
+    block: BlockRng64<TinyMT64>,
+  }
+
+  impl BlockTinyMT64 {
+    /// Buffer the outputs of `random`.
+    pub fn new(random: TinyMT64) -> Self {
+      BlockTinyMT64 { block: BlockRng64::new(random) }
+    }
+  }
+
+  impl SeedableRng for BlockTinyMT64 {
+    type Seed = TinyMT64Seed;
+
+    fn from_seed(seed: Self::Seed) -> Self {
+      Self::new(TinyMT64::from_seed(seed))
+    }
+  }
+
+  impl RngCore for BlockTinyMT64 {
+    /// Take the lower half of a word and discard the upper half, as `TinyMT64::next_u32()`.
+    fn next_u32(&mut self) -> u32 {
+      self.block.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+      self.block.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+      self.block.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+      self.fill_bytes(dest);
+      Ok(())
+    }
+  }
+
+  impl_block_rng_core!(rand_core_06);
+
+  impl_rand_core!(
+    rand_core_06,
+    TinyMT64,
+    TinyMT64Seed,
+    from_seed_u64,
+    u64,
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+      self.fill_bytes(dest);
+      Ok(())
+    }
+  );
+  impl_rand_core!(
+    rand_core_06,
+    TinyMT32,
+    TinyMT32Seed,
+    from_seed_u32,
+    u32,
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+      self.fill_bytes(dest);
+      Ok(())
+    }
+  );
+}
+
+// `TryRngCore` of 0.9 is implemented by the blanket implementation for `RngCore`.
+#[cfg(feature = "rand_core_06")]
+pub use self::v06::{BlockTinyMT32, BlockTinyMT64};
+
+#[cfg(feature = "rand_core_09")]
+mod v09 {
+  use super::*;
+
+  impl_block_rng_core!(rand_core_09);
+  impl_rand_core!(rand_core_09, TinyMT64, TinyMT64Seed, from_seed_u64, u64);
+  impl_rand_core!(rand_core_09, TinyMT32, TinyMT32Seed, from_seed_u32, u32);
+}
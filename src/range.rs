@@ -0,0 +1,145 @@
+//! Bounded integer generation independent of `rand`.
+//!
+//! The numbers are generated by Lemire's nearly divisionless method ("Fast Random Integer
+//! Generation in an Interval", 2019), which is unbiased and usually takes a single output. The
+//! values returned for a given generator state are part of the stable API of this crate: they won't
+//! change in any 1.x release, regardless of the version of `rand`.
+//!
+//! * `TinyMT32` uses one 32-bit output per trial for `gen_below()` and `gen_range_u32()`, and a
+//!   64-bit word made of two outputs `(first << 32) | second` for `gen_range_u64()` and
+//!   `gen_range_i64()`.
+//! * `TinyMT64` uses one 64-bit output per trial for all methods.
+use core::ops::RangeInclusive;
+
+use crate::tinymt32::tinymt32_generate_uint32;
+use crate::tinymt64::tinymt64_generate_uint64;
+use crate::{TinyMT32, TinyMT64};
+
+/// Generate a number r (0 <= r < n) from 32-bit words. `n` must not be 0.
+fn below_u32<F: FnMut() -> u32>(mut next: F, n: u32) -> u32 {
+  let mut m = next() as u64 * n as u64;
+  if (m as u32) < n {
+    let threshold = n.wrapping_neg() % n;
+    while (m as u32) < threshold {
+      m = next() as u64 * n as u64;
+    }
+  }
+  (m >> 32) as u32
+}
+
+/// Generate a number r (0 <= r < n) from 64-bit words. `n` must not be 0.
+pub(crate) fn below_u64<F: FnMut() -> u64>(mut next: F, n: u64) -> u64 {
+  let mut m = next() as u128 * n as u128;
+  if (m as u64) < n {
+    let threshold = n.wrapping_neg() % n;
+    while (m as u64) < threshold {
+      m = next() as u128 * n as u128;
+    }
+  }
+  (m >> 64) as u64
+}
+
+/// Generate a number r (lo <= r <= hi) from 64-bit words, where the whole range of `u64` takes
+/// a word as it is.
+fn range_u64<F: FnMut() -> u64>(mut next: F, range: RangeInclusive<u64>) -> u64 {
+  let (lo, hi) = range.into_inner();
+  assert!(lo <= hi, "empty range: {}..={}", lo, hi);
+  match (hi - lo).checked_add(1) {
+    Some(n) => lo + below_u64(next, n),
+    None => next(),
+  }
+}
+
+/// Map `i64` to `u64` preserving the order.
+fn i64_to_ordered(x: i64) -> u64 {
+  (x as u64) ^ (1 << 63)
+}
+
+impl TinyMT32 {
+  /// Generate a uniformly distributed number r (0 <= r < n).
+  ///
+  /// # Panics
+  /// If `n` is 0.
+  pub fn gen_below(&mut self, n: u32) -> u32 {
+    assert!(n != 0, "gen_below(0)");
+    below_u32(|| tinymt32_generate_uint32(self), n)
+  }
+
+  /// Generate a uniformly distributed number r in the inclusive range `lo..=hi`.
+  ///
+  /// # Panics
+  /// If the range is empty.
+  pub fn gen_range_u32(&mut self, range: RangeInclusive<u32>) -> u32 {
+    let (lo, hi) = range.into_inner();
+    assert!(lo <= hi, "empty range: {}..={}", lo, hi);
+    match (hi - lo).checked_add(1) {
+      Some(n) => lo + below_u32(|| tinymt32_generate_uint32(self), n),
+      None => tinymt32_generate_uint32(self),
+    }
+  }
+
+  /// Generate a uniformly distributed number r in the inclusive range `lo..=hi`.
+  ///
+  /// # Panics
+  /// If the range is empty.
+  pub fn gen_range_u64(&mut self, range: RangeInclusive<u64>) -> u64 {
+    range_u64(|| self.next_word64(), range)
+  }
+
+  /// Generate a uniformly distributed number r in the inclusive range `lo..=hi`.
+  ///
+  /// # Panics
+  /// If the range is empty.
+  pub fn gen_range_i64(&mut self, range: RangeInclusive<i64>) -> i64 {
+    let (lo, hi) = range.into_inner();
+    assert!(lo <= hi, "empty range: {}..={}", lo, hi);
+    let r = range_u64(|| self.next_word64(), i64_to_ordered(lo)..=i64_to_ordered(hi));
+    (r ^ (1 << 63)) as i64
+  }
+
+  /// The 64-bit word made of two 32-bit outputs.
+  fn next_word64(&mut self) -> u64 {
+    let first = tinymt32_generate_uint32(self) as u64;
+    let second = tinymt32_generate_uint32(self) as u64;
+    (first << 32) | second
+  }
+}
+
+impl TinyMT64 {
+  /// Generate a uniformly distributed number r (0 <= r < n).
+  ///
+  /// # Panics
+  /// If `n` is 0.
+  pub fn gen_below(&mut self, n: u64) -> u64 {
+    assert!(n != 0, "gen_below(0)");
+    below_u64(|| tinymt64_generate_uint64(self), n)
+  }
+
+  /// Generate a uniformly distributed number r in the inclusive range `lo..=hi`.
+  ///
+  /// # Panics
+  /// If the range is empty.
+  pub fn gen_range_u32(&mut self, range: RangeInclusive<u32>) -> u32 {
+    let (lo, hi) = range.into_inner();
+    range_u64(|| tinymt64_generate_uint64(self), lo as u64..=hi as u64) as u32
+  }
+
+  /// Generate a uniformly distributed number r in the inclusive range `lo..=hi`.
+  ///
+  /// # Panics
+  /// If the range is empty.
+  pub fn gen_range_u64(&mut self, range: RangeInclusive<u64>) -> u64 {
+    range_u64(|| tinymt64_generate_uint64(self), range)
+  }
+
+  /// Generate a uniformly distributed number r in the inclusive range `lo..=hi`.
+  ///
+  /// # Panics
+  /// If the range is empty.
+  pub fn gen_range_i64(&mut self, range: RangeInclusive<i64>) -> i64 {
+    let (lo, hi) = range.into_inner();
+    assert!(lo <= hi, "empty range: {}..={}", lo, hi);
+    let r = range_u64(|| tinymt64_generate_uint64(self), i64_to_ordered(lo)..=i64_to_ordered(hi));
+    (r ^ (1 << 63)) as i64
+  }
+}
@@ -0,0 +1,115 @@
+//! `std::io::Read` adapter of the generators, available with the `std` feature.
+//!
+//! The reader converts each output to bytes in the chosen byte order and keeps the bytes of the
+//! last output that don't fit in a read for the next one, so the stream doesn't depend on how it's
+//! split into reads. In little endian, the stream is the same as one `fill_bytes()` call of the
+//! whole length.
+use std::io::{self, Read};
+
+use crate::{TinyMT32, TinyMT64};
+
+/// The byte order in which `RandomReader` writes each output.
+#[derive(PartialEq, Eq, Debug, Copy, Clone, Default)]
+pub enum Endian {
+  /// The least significant byte first, as `fill_bytes()`.
+  #[default]
+  Little,
+  /// The most significant byte first.
+  Big,
+}
+
+/// Endless stream of random bytes from `TinyMT64` or `TinyMT32`. Every `read()` fills the whole
+/// buffer, so use `take(n)` to read a limited number of bytes.
+///
+/// ```rust
+/// use std::io::Read;
+/// use tinymt::{RandomReader, TinyMT64};
+///
+/// let mut reader = RandomReader::new(TinyMT64::from_seed_u64(1));
+/// let mut bytes = Vec::new();
+/// reader.by_ref().take(1000).read_to_end(&mut bytes).unwrap();
+/// assert_eq!(1000, bytes.len());
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct RandomReader<R> {
+  random: R,
+  endian: Endian,
+  /// The bytes of the last output; `buffer[position..len]` are not read yet.
+  buffer: [u8; 8],
+  position: usize,
+  len: usize,
+}
+
+impl<R> RandomReader<R> {
+  /// Read from `random` in little endian.
+  pub fn new(random: R) -> Self {
+    Self::with_endian(random, Endian::Little)
+  }
+
+  /// Read from `random` in the byte order `endian`.
+  pub fn with_endian(random: R, endian: Endian) -> Self {
+    RandomReader { random, endian, buffer: [0; 8], position: 0, len: 0 }
+  }
+
+  /// The byte order of the outputs.
+  pub fn endian(&self) -> Endian {
+    self.endian
+  }
+
+  /// The generator, which has advanced past the outputs read so far, including the one with
+  /// unread bytes.
+  pub fn get_ref(&self) -> &R {
+    &self.random
+  }
+
+  /// Unwrap the generator. The unread bytes of the last output are lost.
+  pub fn into_inner(self) -> R {
+    self.random
+  }
+
+  /// Copy the unread bytes of the last output to the beginning of `buf` and return their number.
+  fn read_buffered(&mut self, buf: &mut [u8]) -> usize {
+    let n = (self.len - self.position).min(buf.len());
+    buf[..n].copy_from_slice(&self.buffer[self.position..self.position + n]);
+    self.position += n;
+    n
+  }
+}
+
+macro_rules! impl_random_reader {
+  ($t:ty, $word:ty, $next:ident) => {
+    impl RandomReader<$t> {
+      /// The bytes of the next output in the byte order of the reader.
+      fn next_bytes(&mut self) -> [u8; core::mem::size_of::<$word>()] {
+        let word = self.random.$next();
+        match self.endian {
+          Endian::Little => word.to_le_bytes(),
+          Endian::Big => word.to_be_bytes(),
+        }
+      }
+    }
+
+    impl Read for RandomReader<$t> {
+      fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        const SIZE: usize = core::mem::size_of::<$word>();
+        let start = self.read_buffered(buf);
+        let mut chunks = buf[start..].chunks_exact_mut(SIZE);
+        for chunk in &mut chunks {
+          chunk.copy_from_slice(&self.next_bytes());
+        }
+        let rest = chunks.into_remainder();
+        if !rest.is_empty() {
+          let bytes = self.next_bytes();
+          self.buffer[..SIZE].copy_from_slice(&bytes);
+          self.position = 0;
+          self.len = SIZE;
+          self.read_buffered(rest);
+        }
+        Ok(buf.len())
+      }
+    }
+  };
+}
+
+impl_random_reader!(TinyMT64, u64, next_u64);
+impl_random_reader!(TinyMT32, u32, next_u32);
@@ -0,0 +1,139 @@
+//! Shuffling and sampling of sequences independent of `rand`.
+//!
+//! The algorithms are frozen as well as the bounded integer generation they are built on, so
+//! the results for a given generator state won't change in any 1.x release. An index below `n` is
+//! generated by `gen_below(n)` of `TinyMT64`, and by `gen_below(n)` of `TinyMT32` if `n` fits in
+//! `u32` or `gen_range_u64(0..=n - 1)` otherwise.
+//!
+//! * `shuffle()`: Fisher–Yates shuffle, swapping the i-th element with the element at
+//!   `index_below(i + 1)` for i = len - 1, ..., 1.
+//! * `choose()`: the element at `index_below(len)`.
+//! * `sample_indices()`: Knuth's selection sampling (Algorithm S), which takes one index for each
+//!   of the n candidates until k indices are selected.
+//! * `reservoir_sample()`: reservoir sampling (Algorithm R), which takes one index for each item
+//!   after the reservoir is filled.
+use crate::{TinyMT32, TinyMT64};
+
+/// Iterator over `k` distinct indices sampled from `0..n` in increasing order, created by
+/// `sample_indices()` of `TinyMT64` or `TinyMT32`. It doesn't allocate any memory.
+pub struct SampleIndices<'a, R> {
+  random: &'a mut R,
+  index_below: fn(&mut R, usize) -> usize,
+  n: usize,
+  k: usize,
+  index: usize,
+}
+
+impl<'a, R> Iterator for SampleIndices<'a, R> {
+  type Item = usize;
+
+  fn next(&mut self) -> Option<usize> {
+    while self.k > 0 {
+      let index = self.index;
+      self.index += 1;
+      if (self.index_below)(self.random, self.n - index) < self.k {
+        self.k -= 1;
+        return Some(index);
+      }
+    }
+    None
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (self.k, Some(self.k))
+  }
+}
+
+impl<'a, R> ExactSizeIterator for SampleIndices<'a, R> {}
+
+/// Shuffle `slice` by Fisher–Yates.
+fn shuffle<R, T>(random: &mut R, index_below: fn(&mut R, usize) -> usize, slice: &mut [T]) {
+  for i in (1..slice.len()).rev() {
+    let j = index_below(random, i + 1);
+    slice.swap(i, j);
+  }
+}
+
+/// Fill `reservoir` with items sampled from `iter` by Algorithm R.
+fn reservoir_sample<R, T, I: IntoIterator<Item = T>>(
+  random: &mut R,
+  index_below: fn(&mut R, usize) -> usize,
+  iter: I,
+  reservoir: &mut [T],
+) -> usize {
+  let mut count = 0;
+  for item in iter {
+    if count < reservoir.len() {
+      reservoir[count] = item;
+    } else {
+      let j = index_below(random, count + 1);
+      if j < reservoir.len() {
+        reservoir[j] = item;
+      }
+    }
+    count += 1;
+  }
+  count.min(reservoir.len())
+}
+
+macro_rules! impl_seq {
+  ($t:ty) => {
+    impl $t {
+      /// Shuffle the elements of `slice` in place.
+      pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        shuffle(self, Self::index_below, slice)
+      }
+
+      /// Choose an element of `slice` uniformly, or `None` if `slice` is empty.
+      pub fn choose<'s, T>(&mut self, slice: &'s [T]) -> Option<&'s T> {
+        if slice.is_empty() {
+          None
+        } else {
+          Some(&slice[self.index_below(slice.len())])
+        }
+      }
+
+      /// Sample `k` distinct indices from `0..n` uniformly. The indices are returned in increasing
+      /// order without allocating memory, taking one output for each candidate until `k` indices
+      /// are selected.
+      ///
+      /// # Panics
+      /// If `k` is greater than `n`.
+      pub fn sample_indices(&mut self, n: usize, k: usize) -> SampleIndices<'_, Self> {
+        assert!(k <= n, "sample_indices({}, {}): k must not be greater than n", n, k);
+        SampleIndices { random: self, index_below: Self::index_below, n, k, index: 0 }
+      }
+
+      /// Sample items uniformly from `iter` into `reservoir` without replacement, and return the
+      /// number of the sampled items, which is less than `reservoir.len()` only if `iter` has
+      /// fewer items. The sampled items are not in a uniformly random order.
+      pub fn reservoir_sample<T, I: IntoIterator<Item = T>>(
+        &mut self,
+        iter: I,
+        reservoir: &mut [T],
+      ) -> usize {
+        reservoir_sample(self, Self::index_below, iter, reservoir)
+      }
+    }
+  };
+}
+
+impl_seq!(TinyMT32);
+impl_seq!(TinyMT64);
+
+impl TinyMT32 {
+  /// Generate an index r (0 <= r < n) with the frozen algorithm.
+  fn index_below(&mut self, n: usize) -> usize {
+    match u32::try_from(n) {
+      Ok(n) => self.gen_below(n) as usize,
+      Err(_) => self.gen_range_u64(0..=n as u64 - 1) as usize,
+    }
+  }
+}
+
+impl TinyMT64 {
+  /// Generate an index r (0 <= r < n) with the frozen algorithm.
+  fn index_below(&mut self, n: usize) -> usize {
+    self.gen_below(n as u64) as usize
+  }
+}
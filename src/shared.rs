@@ -0,0 +1,73 @@
+//! Generator shared between the main loop and interrupt handlers, available with the
+//! `critical-section` feature.
+//!
+//! The state is updated inside a critical section of the `critical-section` crate, which the
+//! platform provides, e.g. by disabling interrupts on a single-core microcontroller. A critical
+//! section is held only while one 32-bit output is generated, so `fill_bytes()` doesn't block
+//! interrupts for the whole buffer.
+use core::cell::Cell;
+
+use critical_section::Mutex;
+
+use crate::tinymt32::tinymt32_generate_uint32;
+use crate::TinyMT32;
+
+/// `TinyMT32` that can be used through a shared reference from any context, such as a `static`
+/// accessed by both the main loop and interrupt handlers. Used from a single context, it gives
+/// the same stream as the `TinyMT32` it's created with.
+///
+/// ```rust
+/// use tinymt::{SharedTinyMT32, TinyMT32};
+///
+/// static RANDOM: SharedTinyMT32 = SharedTinyMT32::new(TinyMT32::from_seed_u32(1));
+///
+/// assert_eq!(TinyMT32::from_seed_u32(1).next_u32(), RANDOM.next_u32());
+/// ```
+#[derive(Debug)]
+pub struct SharedTinyMT32 {
+  random: Mutex<Cell<TinyMT32>>,
+}
+
+impl SharedTinyMT32 {
+  /// Share `random`. This is `const`, so it can initialize a `static`.
+  pub const fn new(random: TinyMT32) -> Self {
+    SharedTinyMT32 { random: Mutex::new(Cell::new(random)) }
+  }
+
+  /// Generate a 32-bit unsigned integer as `TinyMT32::next_u32()`.
+  pub fn next_u32(&self) -> u32 {
+    critical_section::with(|cs| {
+      let cell = self.random.borrow(cs);
+      let mut random = cell.get();
+      let value = tinymt32_generate_uint32(&mut random);
+      cell.set(random);
+      value
+    })
+  }
+
+  /// Generate a 64-bit unsigned integer as `TinyMT32::next_u64()`. Both outputs are taken in one
+  /// critical section.
+  pub fn next_u64(&self) -> u64 {
+    critical_section::with(|cs| {
+      let cell = self.random.borrow(cs);
+      let mut random = cell.get();
+      let value = random.next_u64();
+      cell.set(random);
+      value
+    })
+  }
+
+  /// Fill `dest` with random bytes as `TinyMT32::fill_bytes()`. Outputs taken by other contexts
+  /// in the meantime are interleaved between the 4-byte chunks.
+  pub fn fill_bytes(&self, dest: &mut [u8]) {
+    for chunk in dest.chunks_mut(4) {
+      let bytes = self.next_u32().to_le_bytes();
+      chunk.copy_from_slice(&bytes[..chunk.len()]);
+    }
+  }
+
+  /// Replace the generator, e.g. to reseed it.
+  pub fn replace(&self, random: TinyMT32) -> TinyMT32 {
+    critical_section::with(|cs| self.random.borrow(cs).replace(random))
+  }
+}
@@ -0,0 +1,165 @@
+//! Generators shared between threads without a `Mutex`, available with the `sync` feature.
+//!
+//! `AtomicTinyMT64` keeps the 128-bit status in an `AtomicU128` of `portable-atomic` and advances it
+//! by a compare-and-swap loop. It's lock-free on targets with a 128-bit compare-and-swap, such as
+//! x86_64 with `cmpxchg16b` (detected at runtime) and aarch64, and falls back to the locks of
+//! `portable-atomic` elsewhere. `ShardedTinyMT64` reduces the contention by giving each thread
+//! one of several generators on separate cache lines.
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use portable_atomic::AtomicU128;
+use portable_atomic::Ordering::Relaxed;
+
+#[cfg(feature = "alloc")]
+use crate::tinymt64::tinymt64_jump_states;
+use crate::tinymt64::{tinymt64_next_state, tinymt64_temper};
+use crate::TinyMT64;
+
+/// `TinyMT64` that many threads can draw from through a shared reference. Each output advances
+/// the status by one compare-and-swap, so the outputs taken by all threads together are exactly
+/// the stream of the `TinyMT64` it's created with, in the order of the successful swaps.
+#[derive(Debug)]
+pub struct AtomicTinyMT64 {
+  status: AtomicU128,
+  mat1: u32,
+  mat2: u32,
+  tmat: u64,
+}
+
+impl AtomicTinyMT64 {
+  /// Share `random`. This is `const`, so it can initialize a `static`.
+  pub const fn new(random: TinyMT64) -> Self {
+    let status = random.status[0] as u128 | (random.status[1] as u128) << 64;
+    AtomicTinyMT64 {
+      status: AtomicU128::new(status),
+      mat1: random.mat1,
+      mat2: random.mat2,
+      tmat: random.tmat,
+    }
+  }
+
+  /// The generator with the status `status`.
+  fn with_status(&self, status: u128) -> TinyMT64 {
+    TinyMT64 {
+      status: [status as u64, (status >> 64) as u64],
+      mat1: self.mat1,
+      mat2: self.mat2,
+      tmat: self.tmat,
+    }
+  }
+
+  /// Generate a 64-bit unsigned integer as `TinyMT64::next_u64()`.
+  pub fn next_u64(&self) -> u64 {
+    let mut current = self.status.load(Relaxed);
+    loop {
+      let mut random = self.with_status(current);
+      tinymt64_next_state(&mut random);
+      let next = random.status[0] as u128 | (random.status[1] as u128) << 64;
+      match self.status.compare_exchange_weak(current, next, Relaxed, Relaxed) {
+        Ok(_) => return tinymt64_temper(&random),
+        Err(actual) => current = actual,
+      }
+    }
+  }
+
+  /// Generate a 32-bit unsigned integer as `TinyMT64::next_u32()`.
+  pub fn next_u32(&self) -> u32 {
+    self.next_u64() as u32
+  }
+
+  /// Fill `dest` with random bytes as `TinyMT64::fill_bytes()`. Outputs taken by other threads in
+  /// the meantime are interleaved between the 8-byte chunks.
+  pub fn fill_bytes(&self, dest: &mut [u8]) {
+    for chunk in dest.chunks_mut(8) {
+      let bytes = self.next_u64().to_le_bytes();
+      chunk.copy_from_slice(&bytes[..chunk.len()]);
+    }
+  }
+
+  /// A copy of the generator in the current status.
+  pub fn load(&self) -> TinyMT64 {
+    self.with_status(self.status.load(Relaxed))
+  }
+}
+
+/// `AtomicTinyMT64` aligned to its own cache line so that shards don't share one.
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+#[repr(align(128))]
+struct CachePadded(AtomicTinyMT64);
+
+/// Several `AtomicTinyMT64` to spread the contention over. The shard i is the substream of the
+/// base generator jumped ahead by i × 2^64 outputs, so the shards don't overlap unless one of them
+/// draws 2^64 outputs.
+///
+/// With the `std` feature, `next_u64()`, `next_u32()` and `fill_bytes()` use the shard assigned to
+/// the current thread in round robin. Otherwise, pick a shard for each core by `shard()`.
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub struct ShardedTinyMT64 {
+  shards: Box<[CachePadded]>,
+}
+
+#[cfg(feature = "alloc")]
+impl ShardedTinyMT64 {
+  /// The distance between the substreams of two adjacent shards.
+  pub const SHARD_STRIDE: u128 = 1 << 64;
+
+  /// Split `random` into `shards` substreams.
+  ///
+  /// # Panics
+  /// If `shards` is 0.
+  pub fn new(random: TinyMT64, shards: usize) -> Self {
+    assert!(shards != 0, "ShardedTinyMT64 needs at least one shard");
+    let padded = tinymt64_jump_states(&random, Self::SHARD_STRIDE)
+      .take(shards)
+      .map(|random| CachePadded(AtomicTinyMT64::new(random)))
+      .collect::<Vec<_>>();
+    ShardedTinyMT64 { shards: padded.into_boxed_slice() }
+  }
+
+  /// The number of shards.
+  pub fn shard_count(&self) -> usize {
+    self.shards.len()
+  }
+
+  /// The shard `index`.
+  ///
+  /// # Panics
+  /// If `index` is not less than `shard_count()`.
+  pub fn shard(&self, index: usize) -> &AtomicTinyMT64 {
+    &self.shards[index].0
+  }
+
+  /// The shard assigned to the current thread.
+  #[cfg(feature = "std")]
+  pub fn local_shard(&self) -> &AtomicTinyMT64 {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    static NEXT_THREAD: AtomicUsize = AtomicUsize::new(0);
+    std::thread_local! {
+      static THREAD: usize = NEXT_THREAD.fetch_add(1, Ordering::Relaxed);
+    }
+    self.shard(THREAD.with(|thread| *thread) % self.shards.len())
+  }
+
+  /// Generate a 64-bit unsigned integer from the shard of the current thread.
+  #[cfg(feature = "std")]
+  pub fn next_u64(&self) -> u64 {
+    self.local_shard().next_u64()
+  }
+
+  /// Generate a 32-bit unsigned integer from the shard of the current thread.
+  #[cfg(feature = "std")]
+  pub fn next_u32(&self) -> u32 {
+    self.local_shard().next_u32()
+  }
+
+  /// Fill `dest` with random bytes from the shard of the current thread.
+  #[cfg(feature = "std")]
+  pub fn fill_bytes(&self, dest: &mut [u8]) {
+    self.local_shard().fill_bytes(dest)
+  }
+}
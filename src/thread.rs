@@ -0,0 +1,198 @@
+//! Thread-local generators, available with the `std` feature.
+//!
+//! Each thread lazily creates one `TinyMT64` and one `TinyMT32`, seeded through `init_by_array()`
+//! with a 128-bit key from the operating system if the `getrandom` feature is enabled, or from the
+//! hasher keys of `std::collections::hash_map::RandomState`, the time and the thread otherwise.
+//!
+//! If the environment variable `TINYMT_SEED` is set to a `u64` in decimal or in hexadecimal with
+//! the `0x` prefix when the first generator is created, the key is made of the seed and the name
+//! of the thread instead, so a named thread, such as `main` or one spawned by
+//! `std::thread::Builder::name()`, always gives the same stream regardless of the order in which
+//! the threads first use their generators. Threads of the same name give the same stream. An
+//! unnamed thread is keyed by the number of generators of the same type created before in the
+//! process, which is reproducible only if the threads create their generators in a deterministic
+//! order.
+use alloc::vec;
+use alloc::vec::Vec;
+use std::cell::RefCell;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{TinyMT32, TinyMT64};
+
+/// The name of the environment variable to make the thread generators deterministic.
+const SEED_VARIABLE: &str = "TINYMT_SEED";
+
+/// The number of thread generators of `TinyMT64` and `TinyMT32` created so far.
+static CREATED_64: AtomicU64 = AtomicU64::new(0);
+static CREATED_32: AtomicU64 = AtomicU64::new(0);
+
+std::thread_local! {
+  static THREAD_RNG_64: Rc<RefCell<TinyMT64>> = Rc::new(RefCell::new(new_thread_rng64()));
+  static THREAD_RNG_32: Rc<RefCell<TinyMT32>> = Rc::new(RefCell::new(new_thread_rng32()));
+}
+
+/// The value of `TINYMT_SEED`, read once in the process.
+///
+/// # Panics
+/// If `TINYMT_SEED` is set but isn't a `u64`, rather than silently falling back to entropy.
+fn env_seed() -> Option<u64> {
+  static SEED: OnceLock<Option<u64>> = OnceLock::new();
+  *SEED.get_or_init(|| {
+    let value = std::env::var(SEED_VARIABLE).ok()?;
+    let value = value.trim();
+    let seed = match value.strip_prefix("0x").or_else(|| value.strip_prefix("0X")) {
+      Some(hex) => u64::from_str_radix(hex, 16),
+      None => value.parse::<u64>(),
+    };
+    match seed {
+      Ok(seed) => Some(seed),
+      Err(_) => panic!("{} is not a u64: {:?}", SEED_VARIABLE, value),
+    }
+  })
+}
+
+/// The key of a new thread generator under `TINYMT_SEED`: the seed, the length of the thread name
+/// and its bytes in little-endian words, or the seed and `index` for an unnamed thread.
+fn seeded_key(seed: u64, index: u64) -> Vec<u64> {
+  match std::thread::current().name() {
+    Some(name) => {
+      let words = name.as_bytes().chunks(8).map(|chunk| {
+        let mut bytes = [0u8; 8];
+        bytes[..chunk.len()].copy_from_slice(chunk);
+        u64::from_le_bytes(bytes)
+      });
+      [seed, name.len() as u64].into_iter().chain(words).collect()
+    }
+    None => vec![seed, index],
+  }
+}
+
+/// The key of a new thread generator, 128 bits unless `TINYMT_SEED` is set. `created` counts the
+/// generators of the type.
+fn thread_key(created: &AtomicU64) -> Vec<u64> {
+  let index = created.fetch_add(1, Ordering::Relaxed);
+  match env_seed() {
+    Some(seed) => seeded_key(seed, index),
+    None => {
+      #[cfg(feature = "getrandom")]
+      {
+        let mut bytes = [0u8; 16];
+        if getrandom::getrandom(&mut bytes).is_ok() {
+          return bytes
+            .chunks(8)
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+            .collect();
+        }
+      }
+      // the keys of RandomState come from the OS for each thread
+      let mut hasher = RandomState::new().build_hasher();
+      let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_nanos()).unwrap_or(0);
+      hasher.write_u128(nanos);
+      hasher.write_u64(index);
+      let first = hasher.finish();
+      hasher.write_u64(first);
+      vec![first, hasher.finish()]
+    }
+  }
+}
+
+fn new_thread_rng64() -> TinyMT64 {
+  let key = thread_key(&CREATED_64);
+  TinyMT64::builder().seed_array(&key).build().unwrap()
+}
+
+fn new_thread_rng32() -> TinyMT32 {
+  let key = thread_key(&CREATED_32)
+    .into_iter()
+    .flat_map(|word| [word as u32, (word >> 32) as u32])
+    .collect::<Vec<_>>();
+  TinyMT32::builder().seed_array(&key).build().unwrap()
+}
+
+/// Handle to the `TinyMT64` of the current thread, created by `thread_rng64()`. The clones share
+/// the same generator; the handle is neither `Send` nor `Sync`.
+#[derive(Debug, Clone)]
+pub struct ThreadTinyMT64 {
+  random: Rc<RefCell<TinyMT64>>,
+}
+
+/// Handle to the `TinyMT32` of the current thread, created by `thread_rng32()`. The clones share
+/// the same generator; the handle is neither `Send` nor `Sync`.
+#[derive(Debug, Clone)]
+pub struct ThreadTinyMT32 {
+  random: Rc<RefCell<TinyMT32>>,
+}
+
+/// Retrieve the handle to the `TinyMT64` of the current thread.
+pub fn thread_rng64() -> ThreadTinyMT64 {
+  ThreadTinyMT64 { random: THREAD_RNG_64.with(Rc::clone) }
+}
+
+/// Retrieve the handle to the `TinyMT32` of the current thread.
+pub fn thread_rng32() -> ThreadTinyMT32 {
+  ThreadTinyMT32 { random: THREAD_RNG_32.with(Rc::clone) }
+}
+
+macro_rules! impl_thread_rng {
+  ($t:ty) => {
+    impl $t {
+      /// Generate a 32-bit unsigned integer as `next_u32()` of the generator.
+      pub fn next_u32(&mut self) -> u32 {
+        self.random.borrow_mut().next_u32()
+      }
+
+      /// Generate a 64-bit unsigned integer as `next_u64()` of the generator.
+      pub fn next_u64(&mut self) -> u64 {
+        self.random.borrow_mut().next_u64()
+      }
+
+      /// Fill `dest` with random bytes as `fill_bytes()` of the generator.
+      pub fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.random.borrow_mut().fill_bytes(dest)
+      }
+    }
+
+    #[cfg(feature = "rand_core_06")]
+    impl rand_core_06::RngCore for $t {
+      fn next_u32(&mut self) -> u32 {
+        <$t>::next_u32(self)
+      }
+
+      fn next_u64(&mut self) -> u64 {
+        <$t>::next_u64(self)
+      }
+
+      fn fill_bytes(&mut self, dest: &mut [u8]) {
+        <$t>::fill_bytes(self, dest)
+      }
+
+      fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core_06::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+      }
+    }
+
+    #[cfg(feature = "rand_core_09")]
+    impl rand_core_09::RngCore for $t {
+      fn next_u32(&mut self) -> u32 {
+        <$t>::next_u32(self)
+      }
+
+      fn next_u64(&mut self) -> u64 {
+        <$t>::next_u64(self)
+      }
+
+      fn fill_bytes(&mut self, dest: &mut [u8]) {
+        <$t>::fill_bytes(self, dest)
+      }
+    }
+  };
+}
+
+impl_thread_rng!(ThreadTinyMT64);
+impl_thread_rng!(ThreadTinyMT32);
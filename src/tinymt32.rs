@@ -1,5 +1,24 @@
+//! A port of `tinymt32.h` and `tinymt32.c` of the original C implementation. The outputs of the
+//! generate functions are bit-for-bit identical to the corresponding C functions. The `53double`
+//! and `full` functions are extensions of this crate that have no counterpart in C.
+//!
+//! | function                          | interval          | steps per output |
+//! |-----------------------------------|-------------------|------------------|
+//! | `tinymt32_generate_uint32()`      | 0 <= r < 2^32     | 1                |
+//! | `tinymt32_generate_float()`       | 0.0 <= r < 1.0    | 1                |
+//! | `tinymt32_generate_float01()`     | 0.0 <= r < 1.0    | 1                |
+//! | `tinymt32_generate_float12()`     | 1.0 <= r < 2.0    | 1                |
+//! | `tinymt32_generate_float_oc()`    | 0.0 < r <= 1.0    | 2                |
+//! | `tinymt32_generate_float_oo()`    | 0.0 < r < 1.0     | 1                |
+//! | `tinymt32_generate_32double()`    | 0.0 <= r < 1.0    | 1                |
+//! | `tinymt32_generate_53double()`    | 0.0 <= r < 1.0    | 2                |
+//! | `tinymt32_generate_53double_oc()` | 0.0 < r <= 1.0    | 2                |
+//! | `tinymt32_generate_53double_oo()` | 0.0 < r < 1.0     | 2                |
+//! | `tinymt32_generate_float_full()`  | 0.0 <= r < 1.0    | 2 (usually)      |
+//! | `tinymt32_generate_double_full()` | 0.0 <= r < 1.0    | 3 (usually)      |
 use core::cmp::min;
 
+use crate::gf2::Matrix;
 use crate::TinyMT32;
 
 const TINYMT32_MEXP: usize = 127;
@@ -8,11 +27,12 @@ const TINYMT32_SH1: u32 = 10;
 const TINYMT32_SH8: u32 = 8;
 const TINYMT32_MASK: u32 = 0x7fff_ffff_u32;
 const TINYMT32_MUL: f64 = 1.0f64 / 16_777_216.0_f64;
+const TINYMT32_MUL53: f64 = 1.0f64 / 9_007_199_254_740_992.0_f64;
 const MIN_LOOP: usize = 8;
 const PRE_LOOP: usize = 8;
 
 impl TinyMT32 {
-  pub fn new(status: [u32; 4], mat1: u32, mat2: u32, tmat: u32) -> TinyMT32 {
+  pub const fn new(status: [u32; 4], mat1: u32, mat2: u32, tmat: u32) -> TinyMT32 {
     TinyMT32 { status, mat1, mat2, tmat }
   }
 }
@@ -29,7 +49,7 @@ fn ini_func2(x: u32) -> u32 {
 
 /// This function certificate the period of 2^127-1.
 /// @param random tinymt state vector.
-fn period_certification(random: &mut TinyMT32) {
+const fn period_certification(random: &mut TinyMT32) {
   if random.status[0] & TINYMT32_MASK == 0
     && random.status[1] == 0
     && random.status[2] == 0
@@ -45,26 +65,34 @@ fn period_certification(random: &mut TinyMT32) {
 /// This function initializes the internal state array with a 32-bit unsigned integer seed.
 /// @param random tinymt state vector.
 /// @param seed a 32-bit unsigned integer used as a seed.
-pub fn tinymt32_init(random: &mut TinyMT32, seed: u32) {
+pub const fn tinymt32_init(random: &mut TinyMT32, seed: u32) {
   random.status[0] = seed;
   random.status[1] = random.mat1;
   random.status[2] = random.mat2;
   random.status[3] = random.tmat;
-  for i in 1..MIN_LOOP {
+  // `for` loops aren't allowed in const fn
+  let mut i = 1;
+  while i < MIN_LOOP {
     random.status[i & 3] ^= (i as u32).wrapping_add(
       1_812_433_253_u32
         .wrapping_mul(random.status[(i - 1) & 3] ^ (random.status[(i - 1) & 3] >> 30)),
     );
+    i += 1;
   }
   period_certification(random);
-  for _ in 0..PRE_LOOP {
+  let mut i = 0;
+  while i < PRE_LOOP {
     tinymt32_next_state(random);
+    i += 1;
   }
 }
 
 /// This function initializes the internal state array, with an array of 32-bit unsigned integers used as seeds
 /// @param init_key the array of 32-bit integers, used as a seed.
 /// @param key_length the length of init_key.
+///
+/// All arithmetic wraps around as the unsigned arithmetic of the C implementation, so this
+/// function never panics for any `init_key`, including an empty one.
 pub fn tinymt32_init_by_array(random: &mut TinyMT32, init_key: &[u32]) {
   let key_length: usize = init_key.len();
   let lag: usize = 1;
@@ -79,7 +107,7 @@ pub fn tinymt32_init_by_array(random: &mut TinyMT32, init_key: &[u32]) {
   let mut count: usize = if key_length + 1 > MIN_LOOP { key_length + 1 } else { MIN_LOOP };
   let mut r: u32 = ini_func1(st[0] ^ st[mid % size] ^ st[(size - 1) % size]);
   st[mid % size] = st[mid % size].wrapping_add(r);
-  r += key_length as u32;
+  r = r.wrapping_add(key_length as u32);
   st[(mid + lag) % size] = st[(mid + lag) % size].wrapping_add(r);
   st[0] = r;
   count -= 1;
@@ -88,7 +116,7 @@ pub fn tinymt32_init_by_array(random: &mut TinyMT32, init_key: &[u32]) {
   for key in init_key.iter().take(boundary) {
     r = ini_func1(st[i % size] ^ st[(i + mid) % size] ^ st[(i + size - 1) % size]);
     st[(i + mid) % size] = st[(i + mid) % size].wrapping_add(r);
-    r += key + i as u32;
+    r = r.wrapping_add(key.wrapping_add(i as u32));
     st[(i + mid + lag) % size] = st[(i + mid + lag) % size].wrapping_add(r);
     st[i % size] = r;
     i = (i + 1) % size;
@@ -96,7 +124,7 @@ pub fn tinymt32_init_by_array(random: &mut TinyMT32, init_key: &[u32]) {
   for _ in min(count, key_length)..count {
     r = ini_func1(st[i % size] ^ st[(i + mid) % size] ^ st[(i + size - 1) % size]);
     st[(i + mid) % size] = st[(i + mid) % size].wrapping_add(r);
-    r += i as u32;
+    r = r.wrapping_add(i as u32);
     st[(i + mid + lag) % size] = st[(i + mid + lag) % size].wrapping_add(r);
     st[i % size] = r;
     i = (i + 1) % size;
@@ -106,7 +134,7 @@ pub fn tinymt32_init_by_array(random: &mut TinyMT32, init_key: &[u32]) {
       st[i % size].wrapping_add(st[(i + mid) % size]).wrapping_add(st[(i + size - 1) % size]),
     );
     st[(i + mid) % size] ^= r;
-    r -= i as u32;
+    r = r.wrapping_sub(i as u32);
     st[(i + mid + lag) % size] ^= r;
     st[i % size] = r;
     i = (i + 1) % size;
@@ -120,14 +148,14 @@ pub fn tinymt32_init_by_array(random: &mut TinyMT32, init_key: &[u32]) {
 /// This function always returns 127
 /// @return always 127
 #[inline]
-pub fn tinymt32_get_mexp(_: &TinyMT32) -> usize {
+pub const fn tinymt32_get_mexp(_: &TinyMT32) -> usize {
   TINYMT32_MEXP
 }
 
 /// This function changes internal state of tinymt32. Users should not call this function directly.
 /// @param random tinymt internal status
 #[inline]
-pub fn tinymt32_next_state(random: &mut TinyMT32) {
+pub const fn tinymt32_next_state(random: &mut TinyMT32) {
   let mut y: u32 = random.status[3];
   let mut x: u32 = (random.status[0] & TINYMT32_MASK) ^ random.status[1] ^ random.status[2];
   x ^= x << TINYMT32_SH0;
@@ -144,7 +172,7 @@ pub fn tinymt32_next_state(random: &mut TinyMT32) {
 /// @param random tinymt internal status
 /// @return 32-bit unsigned pseudorandom number
 #[inline]
-pub fn tinymt32_temper(random: &mut TinyMT32) -> u32 {
+pub const fn tinymt32_temper(random: &mut TinyMT32) -> u32 {
   let mut t0: u32 = random.status[3];
   // defined(LINEARITY_CHECK)
   // t1 = random->status[0]^ (random->status[2] >> TINYMT32_SH8);
@@ -157,7 +185,7 @@ pub fn tinymt32_temper(random: &mut TinyMT32) -> u32 {
 /// @param random tinymt internal status
 /// @return floating point number r (1.0 <= r < 2.0)
 #[inline]
-pub fn tinymt32_temper_conv(random: &mut TinyMT32) -> f32 {
+pub const fn tinymt32_temper_conv(random: &mut TinyMT32) -> f32 {
   let mut t0: u32 = random.status[3];
   // defined(LINEARITY_CHECK)
   // t1 = random->status[0]^ (random->status[2] >> TINYMT32_SH8);
@@ -170,7 +198,7 @@ pub fn tinymt32_temper_conv(random: &mut TinyMT32) -> f32 {
 /// This function outputs floating point number from internal state. Users should not call this function directly.
 /// @return floating point number r (1.0 < r < 2.0)
 #[inline]
-pub fn tinymt32_temper_conv_open(random: &mut TinyMT32) -> f32 {
+pub const fn tinymt32_temper_conv_open(random: &mut TinyMT32) -> f32 {
   let mut t0: u32 = random.status[3];
   // defined(LINEARITY_CHECK)
   // t1 = random->status[0] ^ (random->status[2] >> TINYMT32_SH8);
@@ -183,7 +211,7 @@ pub fn tinymt32_temper_conv_open(random: &mut TinyMT32) -> f32 {
 /// This function outputs 32-bit unsigned integer from internal state.
 /// @return 32-bit unsigned integer r (0 <= r < 2^32)
 #[inline]
-pub fn tinymt32_generate_uint32(random: &mut TinyMT32) -> u32 {
+pub const fn tinymt32_generate_uint32(random: &mut TinyMT32) -> u32 {
   tinymt32_next_state(random);
   tinymt32_temper(random)
 }
@@ -191,7 +219,7 @@ pub fn tinymt32_generate_uint32(random: &mut TinyMT32) -> u32 {
 /// This function outputs floating point number from internal state. This function is implemented using multiplying by (1 / 2^24). floating point multiplication is faster than using union trick in my Intel CPU.
 /// @return floating point number r (0.0 <= r < 1.0)
 #[inline]
-pub fn tinymt32_generate_float(random: &mut TinyMT32) -> f32 {
+pub const fn tinymt32_generate_float(random: &mut TinyMT32) -> f32 {
   tinymt32_next_state(random);
   ((tinymt32_temper(random) >> 8) as f64 * TINYMT32_MUL) as f32
 }
@@ -199,7 +227,7 @@ pub fn tinymt32_generate_float(random: &mut TinyMT32) -> f32 {
 /// This function outputs floating point number from internal state. This function is implemented using union trick.
 /// @return floating point number r (1.0 <= r < 2.0)
 #[inline]
-pub fn tinymt32_generate_float12(random: &mut TinyMT32) -> f32 {
+pub const fn tinymt32_generate_float12(random: &mut TinyMT32) -> f32 {
   tinymt32_next_state(random);
   tinymt32_temper_conv(random)
 }
@@ -208,15 +236,19 @@ pub fn tinymt32_generate_float12(random: &mut TinyMT32) -> f32 {
 /// This function is implemented using union trick.
 /// @return floating point number r (0.0 <= r < 1.0)
 #[inline]
-pub fn tinymt32_generate_float01(random: &mut TinyMT32) -> f32 {
+pub const fn tinymt32_generate_float01(random: &mut TinyMT32) -> f32 {
   tinymt32_next_state(random);
   tinymt32_temper_conv(random) - 1.0f32
 }
 
 /// This function outputs floating point number from internal state. This function may return 1.0 and never returns 0.0.
+///
+/// As in the original implementation, the internal state advances twice for each number: once
+/// here and once in `tinymt32_generate_float()`. The sequence is therefore not `1.0 - r` of the
+/// sequence of `tinymt32_generate_float()`, and it is kept this way to stay compatible with C.
 /// @return floating point number r (0.0 < r <= 1.0)
 #[inline]
-pub fn tinymt32_generate_float_oc(random: &mut TinyMT32) -> f32 {
+pub const fn tinymt32_generate_float_oc(random: &mut TinyMT32) -> f32 {
   tinymt32_next_state(random);
   1.0f32 - tinymt32_generate_float(random)
 }
@@ -224,7 +256,7 @@ pub fn tinymt32_generate_float_oc(random: &mut TinyMT32) -> f32 {
 /// This function outputs floating point number from internal state. This function returns neither 0.0 nor 1.0.
 /// @return floating point number r (0.0 < r < 1.0)
 #[inline]
-pub fn tinymt32_generate_float_oo(random: &mut TinyMT32) -> f32 {
+pub const fn tinymt32_generate_float_oo(random: &mut TinyMT32) -> f32 {
   tinymt32_next_state(random);
   tinymt32_temper_conv_open(random) - 1.0f32
 }
@@ -232,7 +264,160 @@ pub fn tinymt32_generate_float_oo(random: &mut TinyMT32) -> f32 {
 /// This function outputs double precision floating point number from internal state. The returned value has 32-bit precision.  In other words, this function makes one double precision floating point number from one 32-bit unsigned integer.
 /// @return floating point number r (0.0 <= r < 1.0)
 #[inline]
-pub fn tinymt32_generate_32double(random: &mut TinyMT32) -> f64 {
+pub const fn tinymt32_generate_32double(random: &mut TinyMT32) -> f64 {
   tinymt32_next_state(random);
   tinymt32_temper(random) as f64 * (1.0f64 / 4_294_967_296.0_f64)
 }
+
+/// This function combines two 32-bit outputs into a 53-bit unsigned integer as `genrand_res53()`
+/// of MT19937: the upper 27 bits of the first output and the upper 26 bits of the second.
+#[inline]
+const fn generate_53bits(random: &mut TinyMT32) -> u64 {
+  let a = (tinymt32_generate_uint32(random) >> 5) as u64;
+  let b = (tinymt32_generate_uint32(random) >> 6) as u64;
+  (a << 26) | b
+}
+
+/// This function outputs double precision floating point number from internal state. The returned
+/// value has 53-bit precision, made from two 32-bit unsigned integers as `genrand_res53()` of
+/// MT19937. The internal state advances twice for each number.
+/// @return floating point number r (0.0 <= r < 1.0)
+#[inline]
+pub const fn tinymt32_generate_53double(random: &mut TinyMT32) -> f64 {
+  generate_53bits(random) as f64 * TINYMT32_MUL53
+}
+
+/// This function outputs double precision floating point number from internal state. The returned
+/// value has 53-bit precision and the internal state advances twice for each number. This function
+/// may return 1.0 and never returns 0.0.
+/// @return floating point number r (0.0 < r <= 1.0)
+#[inline]
+pub const fn tinymt32_generate_53double_oc(random: &mut TinyMT32) -> f64 {
+  (generate_53bits(random) + 1) as f64 * TINYMT32_MUL53
+}
+
+/// This function outputs double precision floating point number from internal state. The lowest
+/// bit of the 53-bit integer is always set so that the result is exact, which leaves 52 bits of
+/// randomness. The internal state advances twice for each number. This function returns neither
+/// 0.0 nor 1.0.
+/// @return floating point number r (0.0 < r < 1.0)
+#[inline]
+pub const fn tinymt32_generate_53double_oo(random: &mut TinyMT32) -> f64 {
+  (generate_53bits(random) | 1) as f64 * TINYMT32_MUL53
+}
+
+/// This function draws the biased exponent of a uniform real number in [0, 1) rounded down to a
+/// floating point number, from the leading zeros of the bit stream of 32-bit outputs: the number
+/// is in [1/2, 1) with probability 1/2, in [1/4, 1/2) with probability 1/4, and so on down to the
+/// subnormal numbers of the exponent 0.
+/// @param exponent the biased exponent of [1/2, 1), 126 for f32 and 1022 for f64.
+#[inline]
+const fn generate_exponent(random: &mut TinyMT32, mut exponent: u32) -> u32 {
+  loop {
+    let x = tinymt32_generate_uint32(random);
+    let zeros = x.leading_zeros();
+    if zeros >= exponent {
+      return 0;
+    }
+    exponent -= zeros;
+    if x != 0 {
+      return exponent;
+    }
+  }
+}
+
+/// This function outputs floating point number from internal state. Unlike the other functions
+/// that return multiples of a fixed step, every `f32` in [0.0, 1.0), including the subnormal
+/// numbers, can be returned with the probability of the width of the interval it represents. The
+/// exponent is drawn from the leading zeros of the outputs and the mantissa from one more output,
+/// so the internal state usually advances twice for each number.
+/// @return floating point number r (0.0 <= r < 1.0)
+#[inline]
+pub const fn tinymt32_generate_float_full(random: &mut TinyMT32) -> f32 {
+  let exponent = generate_exponent(random, 126);
+  let mantissa = tinymt32_generate_uint32(random) >> 9;
+  f32::from_bits((exponent << 23) | mantissa)
+}
+
+/// This function outputs double precision floating point number from internal state. Every `f64`
+/// in [0.0, 1.0), including the subnormal numbers, can be returned with the probability of the
+/// width of the interval it represents. The exponent is drawn from the leading zeros of the
+/// outputs and the 52-bit mantissa from two more outputs, so the internal state usually advances
+/// three times for each number.
+/// @return floating point number r (0.0 <= r < 1.0)
+#[inline]
+pub const fn tinymt32_generate_double_full(random: &mut TinyMT32) -> f64 {
+  let exponent = generate_exponent(random, 1022) as u64;
+  let a = tinymt32_generate_uint32(random) as u64;
+  let b = (tinymt32_generate_uint32(random) >> 12) as u64;
+  f64::from_bits((exponent << 52) | (a << 20) | b)
+}
+
+/// This function generates an array of 32-bit unsigned integers from a tinymt initialized by
+/// `tinymt32_init()` with the specified parameters and seed. Since this is a const fn, it can be
+/// used to fill `static` or `const` tables at compile time.
+/// @param mat1 parameter mat1 of tinymt32.
+/// @param mat2 parameter mat2 of tinymt32.
+/// @param tmat parameter tmat of tinymt32.
+/// @param seed a 32-bit unsigned integer used as a seed.
+/// @return the first N outputs of `tinymt32_generate_uint32()`
+pub const fn tinymt32_const_array<const N: usize>(
+  mat1: u32,
+  mat2: u32,
+  tmat: u32,
+  seed: u32,
+) -> [u32; N] {
+  let mut random = TinyMT32::new([0, 0, 0, 0], mat1, mat2, tmat);
+  tinymt32_init(&mut random, seed);
+  let mut array = [0u32; N];
+  let mut i = 0;
+  while i < N {
+    array[i] = tinymt32_generate_uint32(&mut random);
+    i += 1;
+  }
+  array
+}
+
+/// Pack the 127-bit internal state into an integer; the masked-out MSB of status[0] is excluded.
+fn state_to_bits(status: &[u32; 4]) -> u128 {
+  (status[0] & TINYMT32_MASK) as u128
+    | (status[1] as u128) << 32
+    | (status[2] as u128) << 64
+    | (status[3] as u128) << 96
+}
+
+/// Unpack the 127-bit internal state packed by `state_to_bits()`.
+fn bits_to_state(bits: u128) -> [u32; 4] {
+  [bits as u32, (bits >> 32) as u32, (bits >> 64) as u32, (bits >> 96) as u32]
+}
+
+/// The transition matrix of `tinymt32_next_state()` for the parameters of the specified tinymt.
+fn transition_matrix(random: &TinyMT32) -> Matrix {
+  Matrix::from_fn(|bits| {
+    let mut work = TinyMT32 { status: bits_to_state(bits), ..*random };
+    tinymt32_next_state(&mut work);
+    state_to_bits(&work.status)
+  })
+}
+
+/// This function determines whether the parameters of the tinymt give the maximal period
+/// 2^127-1 for every non-zero internal state.
+pub(crate) fn tinymt32_has_maximal_period(random: &TinyMT32) -> bool {
+  transition_matrix(random).has_maximal_order(state_to_bits(&[u32::MAX; 4]))
+}
+
+/// This function changes the internal state of tinymt32 to the one after the specified number
+/// of steps, that is, the same as calling `tinymt32_next_state()` `steps` times, but in
+/// O(log steps) time. This works for any parameter set.
+/// @param random tinymt state vector.
+/// @param steps the number of steps to jump ahead.
+pub fn tinymt32_jump(random: &mut TinyMT32, steps: u128) {
+  if steps == 0 {
+    return;
+  }
+  // The MSB of status[0] doesn't affect the following states, but it is used for tempering, so
+  // the last step must be taken by the actual tinymt32_next_state().
+  let bits = transition_matrix(random).apply_pow(state_to_bits(&random.status), steps - 1);
+  random.status = bits_to_state(bits);
+  tinymt32_next_state(random);
+}
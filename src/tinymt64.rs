@@ -1,5 +1,25 @@
+//! A port of `tinymt64.h` and `tinymt64.c` of the original C implementation. The outputs of the
+//! generate functions are bit-for-bit identical to the corresponding C functions. The `float`
+//! and `full` functions are extensions of this crate that have no counterpart in C.
+//!
+//! | function                          | interval          | steps per output |
+//! |-----------------------------------|-------------------|------------------|
+//! | `tinymt64_generate_uint64()`      | 0 <= r < 2^64     | 1                |
+//! | `tinymt64_generate_double()`      | 0.0 <= r < 1.0    | 1                |
+//! | `tinymt64_generate_double01()`    | 0.0 <= r < 1.0    | 1                |
+//! | `tinymt64_generate_double12()`    | 1.0 <= r < 2.0    | 1                |
+//! | `tinymt64_generate_double_oc()`   | 0.0 < r <= 1.0    | 1                |
+//! | `tinymt64_generate_double_oo()`   | 0.0 < r < 1.0     | 1                |
+//! | `tinymt64_generate_float()`       | 0.0 <= r < 1.0    | 1                |
+//! | `tinymt64_generate_float12()`     | 1.0 <= r < 2.0    | 1                |
+//! | `tinymt64_generate_float_oc()`    | 0.0 < r <= 1.0    | 1                |
+//! | `tinymt64_generate_float_oo()`    | 0.0 < r < 1.0     | 1                |
+//! | `tinymt64_generate_float_pair()`  | 0.0 <= r < 1.0    | 1 per two        |
+//! | `tinymt64_generate_double_full()` | 0.0 <= r < 1.0    | 2 (usually)      |
+//! | `tinymt64_generate_float_full()`  | 0.0 <= r < 1.0    | 2 (usually)      |
 use core::cmp::min;
 
+use crate::gf2::Matrix;
 use crate::TinyMT64;
 
 const TINYMT64_MEXP: usize = 127;
@@ -8,10 +28,11 @@ const TINYMT64_SH1: u64 = 11;
 const TINYMT64_SH8: u64 = 8;
 const TINYMT64_MASK: u64 = 0x7fff_ffff_ffff_ffff_u64;
 const TINYMT64_MUL: f64 = 1.0 / 9_007_199_254_740_992.0;
+const TINYMT64_FLOAT_MUL: f32 = 1.0 / 16_777_216.0;
 const MIN_LOOP: usize = 8;
 
 impl TinyMT64 {
-  pub fn new(status: [u64; 2], mat1: u32, mat2: u32, tmat: u64) -> TinyMT64 {
+  pub const fn new(status: [u64; 2], mat1: u32, mat2: u32, tmat: u64) -> TinyMT64 {
     TinyMT64 { status, mat1, mat2, tmat }
   }
 }
@@ -30,7 +51,7 @@ fn ini_func2(x: u64) -> u64 {
 
 /// This function certificate the period of 2^127-1.
 #[inline]
-fn period_certification(random: &mut TinyMT64) {
+const fn period_certification(random: &mut TinyMT64) {
   if random.status[0] & TINYMT64_MASK == 0 && random.status[1] == 0 {
     random.status[0] = 'T' as u64;
     random.status[1] = 'M' as u64;
@@ -39,14 +60,17 @@ fn period_certification(random: &mut TinyMT64) {
 
 /// This function initializes the internal state array with a 64-bit unsigned integer seed.
 /// @param seed a 64-bit unsigned integer used as a seed.
-pub fn tinymt64_init(random: &mut TinyMT64, seed: u64) {
+pub const fn tinymt64_init(random: &mut TinyMT64, seed: u64) {
   random.status[0] = seed ^ ((random.mat1 as u64) << 32);
   random.status[1] = (random.mat2 as u64) ^ random.tmat;
-  for i in 1..MIN_LOOP {
+  // `for` loops aren't allowed in const fn
+  let mut i = 1;
+  while i < MIN_LOOP {
     random.status[i & 1] ^= (i as u64).wrapping_add(
       6_364_136_223_846_793_005_u64
         .wrapping_mul(random.status[(i - 1) & 1] ^ (random.status[(i - 1) & 1] >> 62)),
     );
+    i += 1;
   }
   period_certification(random);
 }
@@ -54,6 +78,9 @@ pub fn tinymt64_init(random: &mut TinyMT64, seed: u64) {
 /// This function initializes the internal state array, with an array of 64-bit unsigned integers used as seeds
 /// @param init_key the array of 64-bit integers, used as a seed.
 /// @param key_length the length of init_key.
+///
+/// All arithmetic wraps around as the unsigned arithmetic of the C implementation, so this
+/// function never panics for any `init_key`, including an empty one.
 pub fn tinymt64_init_by_array(random: &mut TinyMT64, init_key: &[u64]) {
   let lag: usize = 1;
   let mid: usize = 1;
@@ -63,9 +90,9 @@ pub fn tinymt64_init_by_array(random: &mut TinyMT64, init_key: &[u64]) {
   let mut st: [u64; 4] = [0, random.mat1 as u64, random.mat2 as u64, random.tmat];
   let mut count: usize = if key_length + 1 > MIN_LOOP { key_length + 1 } else { MIN_LOOP };
   let mut r: u64 = ini_func1(st[0] ^ st[mid % size] ^ st[(size - 1) % size]);
-  st[mid % size] += r;
-  r += key_length as u64;
-  st[(mid + lag) % size] += r;
+  st[mid % size] = st[mid % size].wrapping_add(r);
+  r = r.wrapping_add(key_length as u64);
+  st[(mid + lag) % size] = st[(mid + lag) % size].wrapping_add(r);
   st[0] = r;
   count -= 1;
   let mut i = 1;
@@ -73,7 +100,7 @@ pub fn tinymt64_init_by_array(random: &mut TinyMT64, init_key: &[u64]) {
   for key in init_key.iter().take(boundary) {
     r = ini_func1(st[i] ^ st[(i + mid) % size] ^ st[(i + size - 1) % size]);
     st[(i + mid) % size] = st[(i + mid) % size].wrapping_add(r);
-    r += key + i as u64;
+    r = r.wrapping_add(key.wrapping_add(i as u64));
     st[(i + mid + lag) % size] = st[(i + mid + lag) % size].wrapping_add(r);
     st[i] = r;
     i = (i + 1) % size;
@@ -81,7 +108,7 @@ pub fn tinymt64_init_by_array(random: &mut TinyMT64, init_key: &[u64]) {
   for _ in boundary..count {
     r = ini_func1(st[i] ^ st[(i + mid) % size] ^ st[(i + size - 1) % size]);
     st[(i + mid) % size] = st[(i + mid) % size].wrapping_add(r);
-    r += i as u64;
+    r = r.wrapping_add(i as u64);
     st[(i + mid + lag) % size] = st[(i + mid + lag) % size].wrapping_add(r);
     st[i] = r;
     i = (i + 1) % size;
@@ -89,7 +116,7 @@ pub fn tinymt64_init_by_array(random: &mut TinyMT64, init_key: &[u64]) {
   for _ in 0..size {
     r = ini_func2(st[i].wrapping_add(st[(i + mid) % size]).wrapping_add(st[(i + size - 1) % size]));
     st[(i + mid) % size] ^= r;
-    r -= i as u64;
+    r = r.wrapping_sub(i as u64);
     st[(i + mid + lag) % size] ^= r;
     st[i] = r;
     i = (i + 1) % size;
@@ -101,7 +128,7 @@ pub fn tinymt64_init_by_array(random: &mut TinyMT64, init_key: &[u64]) {
 
 /// This function always returns 127.
 #[inline]
-pub fn tinymt64_get_mexp(_: &TinyMT64) -> usize {
+pub const fn tinymt64_get_mexp(_: &TinyMT64) -> usize {
   TINYMT64_MEXP
 }
 
@@ -110,7 +137,7 @@ pub fn tinymt64_get_mexp(_: &TinyMT64) -> usize {
  * @param random tinymt internal status
  */
 #[inline]
-pub fn tinymt64_next_state(random: &mut TinyMT64) {
+pub const fn tinymt64_next_state(random: &mut TinyMT64) {
   random.status[0] &= TINYMT64_MASK;
   let mut x: u64 = random.status[0] ^ random.status[1];
   x ^= x << TINYMT64_SH0;
@@ -126,7 +153,7 @@ pub fn tinymt64_next_state(random: &mut TinyMT64) {
 /// This function outputs 64-bit unsigned integer from internal state. Users should not call this function directly.
 /// @return 64-bit unsigned pseudorandom number
 #[inline]
-pub fn tinymt64_temper(random: &TinyMT64) -> u64 {
+pub const fn tinymt64_temper(random: &TinyMT64) -> u64 {
   // defined(LINEARITY_CHECK)
   // x = random->status[0] ^ random->status[1];
   let mut x = random.status[0].wrapping_add(random.status[1]);
@@ -137,7 +164,7 @@ pub fn tinymt64_temper(random: &TinyMT64) -> u64 {
 /// This function outputs floating point number from internal state. Users should not call this function directly.
 /// @return floating point number r (1.0 <= r < 2.0)
 #[inline]
-pub fn tinymt64_temper_conv(random: &TinyMT64) -> f64 {
+pub const fn tinymt64_temper_conv(random: &TinyMT64) -> f64 {
   // defined(LINEARITY_CHECK)
   // x = random->status[0] ^ random->status[1];
   let mut x = random.status[0].wrapping_add(random.status[1]);
@@ -149,7 +176,7 @@ pub fn tinymt64_temper_conv(random: &TinyMT64) -> f64 {
 /// This function outputs floating point number from internal state. Users should not call this function directly.
 /// @return floating point number r (1.0 < r < 2.0)
 #[inline]
-pub fn tinymt64_temper_conv_open(random: &TinyMT64) -> f64 {
+pub const fn tinymt64_temper_conv_open(random: &TinyMT64) -> f64 {
   // defined(LINEARITY_CHECK)
   // x = random->status[0] ^ random->status[1];
   let mut x = random.status[0].wrapping_add(random.status[1]);
@@ -161,7 +188,7 @@ pub fn tinymt64_temper_conv_open(random: &TinyMT64) -> f64 {
 /// This function outputs 64-bit unsigned integer from internal state.
 /// @return 64-bit unsigned integer r (0 <= r < 2^64)
 #[inline]
-pub fn tinymt64_generate_uint64(random: &mut TinyMT64) -> u64 {
+pub const fn tinymt64_generate_uint64(random: &mut TinyMT64) -> u64 {
   tinymt64_next_state(random);
   tinymt64_temper(random)
 }
@@ -170,7 +197,7 @@ pub fn tinymt64_generate_uint64(random: &mut TinyMT64) -> u64 {
 /// using multiplying by (1 / 2^53).
 /// @return floating point number r (0.0 <= r < 1.0)
 #[inline]
-pub fn tinymt64_generate_double(random: &mut TinyMT64) -> f64 {
+pub const fn tinymt64_generate_double(random: &mut TinyMT64) -> f64 {
   tinymt64_next_state(random);
   ((tinymt64_temper(random) >> 11) as f64) * TINYMT64_MUL
 }
@@ -179,7 +206,7 @@ pub fn tinymt64_generate_double(random: &mut TinyMT64) -> f64 {
 /// using union trick.
 /// @return floating point number r (0.0 <= r < 1.0)
 #[inline]
-pub fn tinymt64_generate_double01(random: &mut TinyMT64) -> f64 {
+pub const fn tinymt64_generate_double01(random: &mut TinyMT64) -> f64 {
   tinymt64_next_state(random);
   tinymt64_temper_conv(random) - 1.0
 }
@@ -188,7 +215,7 @@ pub fn tinymt64_generate_double01(random: &mut TinyMT64) -> f64 {
 /// using union trick.
 /// @return floating point number r (1.0 <= r < 2.0)
 #[inline]
-pub fn tinymt64_generate_double12(random: &mut TinyMT64) -> f64 {
+pub const fn tinymt64_generate_double12(random: &mut TinyMT64) -> f64 {
   tinymt64_next_state(random);
   tinymt64_temper_conv(random)
 }
@@ -197,7 +224,7 @@ pub fn tinymt64_generate_double12(random: &mut TinyMT64) -> f64 {
 /// using union trick.
 /// @return floating point number r (0.0 < r <= 1.0)
 #[inline]
-pub fn tinymt64_generate_double_oc(random: &mut TinyMT64) -> f64 {
+pub const fn tinymt64_generate_double_oc(random: &mut TinyMT64) -> f64 {
   tinymt64_next_state(random);
   2.0 - tinymt64_temper_conv(random)
 }
@@ -205,7 +232,183 @@ pub fn tinymt64_generate_double_oc(random: &mut TinyMT64) -> f64 {
 /// This function outputs floating point number from internal state. This function is implemented
 /// using union trick.
 /// @return floating point number r (0.0 < r < 1.0)
-pub fn tinymt64_generate_double_oo(random: &mut TinyMT64) -> f64 {
+pub const fn tinymt64_generate_double_oo(random: &mut TinyMT64) -> f64 {
   tinymt64_next_state(random);
   tinymt64_temper_conv_open(random) - 1.0
 }
+
+/// This function outputs single precision floating point number from internal state. The upper
+/// 24 bits of the 64-bit output are multiplied by (1 / 2^24), so the result never rounds up to 1.0
+/// unlike casting the result of `tinymt64_generate_double()` to `f32`.
+/// @return floating point number r (0.0 <= r < 1.0)
+#[inline]
+pub const fn tinymt64_generate_float(random: &mut TinyMT64) -> f32 {
+  tinymt64_next_state(random);
+  (tinymt64_temper(random) >> 40) as f32 * TINYMT64_FLOAT_MUL
+}
+
+/// This function outputs single precision floating point number from internal state. This
+/// function may return 1.0 and never returns 0.0.
+/// @return floating point number r (0.0 < r <= 1.0)
+#[inline]
+pub const fn tinymt64_generate_float_oc(random: &mut TinyMT64) -> f32 {
+  tinymt64_next_state(random);
+  ((tinymt64_temper(random) >> 40) + 1) as f32 * TINYMT64_FLOAT_MUL
+}
+
+/// This function outputs single precision floating point number from internal state. The upper
+/// 23 bits of the 64-bit output are placed at the odd multiples of (1 / 2^24), so this function
+/// returns neither 0.0 nor 1.0.
+/// @return floating point number r (0.0 < r < 1.0)
+#[inline]
+pub const fn tinymt64_generate_float_oo(random: &mut TinyMT64) -> f32 {
+  tinymt64_next_state(random);
+  ((tinymt64_temper(random) >> 40) | 1) as f32 * TINYMT64_FLOAT_MUL
+}
+
+/// This function outputs single precision floating point number from internal state. This
+/// function is implemented using union trick with the upper 23 bits of the 64-bit output.
+/// @return floating point number r (1.0 <= r < 2.0)
+#[inline]
+pub const fn tinymt64_generate_float12(random: &mut TinyMT64) -> f32 {
+  tinymt64_next_state(random);
+  f32::from_bits(((tinymt64_temper(random) >> 41) as u32) | 0x3f80_0000_u32)
+}
+
+/// This function outputs two single precision floating point numbers from one 64-bit output, the
+/// first from the upper 24 bits of the upper half and the second from the upper 24 bits of the
+/// lower half. This halves the cost per number when a large amount of `f32` is needed.
+/// @return two floating point numbers r (0.0 <= r < 1.0)
+#[inline]
+pub const fn tinymt64_generate_float_pair(random: &mut TinyMT64) -> [f32; 2] {
+  tinymt64_next_state(random);
+  let x = tinymt64_temper(random);
+  [(x >> 40) as f32 * TINYMT64_FLOAT_MUL, ((x as u32) >> 8) as f32 * TINYMT64_FLOAT_MUL]
+}
+
+/// This function draws the biased exponent of a uniform real number in [0, 1) rounded down to a
+/// floating point number, from the leading zeros of the bit stream of 64-bit outputs: the number
+/// is in [1/2, 1) with probability 1/2, in [1/4, 1/2) with probability 1/4, and so on down to the
+/// subnormal numbers of the exponent 0.
+/// @param exponent the biased exponent of [1/2, 1), 126 for f32 and 1022 for f64.
+#[inline]
+const fn generate_exponent(random: &mut TinyMT64, mut exponent: u64) -> u64 {
+  loop {
+    let x = tinymt64_generate_uint64(random);
+    let zeros = x.leading_zeros() as u64;
+    if zeros >= exponent {
+      return 0;
+    }
+    exponent -= zeros;
+    if x != 0 {
+      return exponent;
+    }
+  }
+}
+
+/// This function outputs double precision floating point number from internal state. Unlike the
+/// other functions that return multiples of a fixed step, every `f64` in [0.0, 1.0), including
+/// the subnormal numbers, can be returned with the probability of the width of the interval it
+/// represents. The exponent is drawn from the leading zeros of the outputs and the mantissa from
+/// one more output, so the internal state usually advances twice for each number.
+/// @return floating point number r (0.0 <= r < 1.0)
+#[inline]
+pub const fn tinymt64_generate_double_full(random: &mut TinyMT64) -> f64 {
+  let exponent = generate_exponent(random, 1022);
+  let mantissa = tinymt64_generate_uint64(random) >> 12;
+  f64::from_bits((exponent << 52) | mantissa)
+}
+
+/// This function outputs single precision floating point number from internal state. Every `f32`
+/// in [0.0, 1.0), including the subnormal numbers, can be returned with the probability of the
+/// width of the interval it represents. The internal state usually advances twice for each
+/// number.
+/// @return floating point number r (0.0 <= r < 1.0)
+#[inline]
+pub const fn tinymt64_generate_float_full(random: &mut TinyMT64) -> f32 {
+  let exponent = generate_exponent(random, 126) as u32;
+  let mantissa = (tinymt64_generate_uint64(random) >> 41) as u32;
+  f32::from_bits((exponent << 23) | mantissa)
+}
+
+/// This function generates an array of 64-bit unsigned integers from a tinymt initialized by
+/// `tinymt64_init()` with the specified parameters and seed. Since this is a const fn, it can be
+/// used to fill `static` or `const` tables at compile time.
+/// @param seed a 64-bit unsigned integer used as a seed.
+/// @return the first N outputs of `tinymt64_generate_uint64()`
+pub const fn tinymt64_const_array<const N: usize>(
+  mat1: u32,
+  mat2: u32,
+  tmat: u64,
+  seed: u64,
+) -> [u64; N] {
+  let mut random = TinyMT64::new([0, 0], mat1, mat2, tmat);
+  tinymt64_init(&mut random, seed);
+  let mut array = [0u64; N];
+  let mut i = 0;
+  while i < N {
+    array[i] = tinymt64_generate_uint64(&mut random);
+    i += 1;
+  }
+  array
+}
+
+/// Pack the 127-bit internal state into an integer; the masked-out MSB of status[0] is excluded.
+fn state_to_bits(status: &[u64; 2]) -> u128 {
+  (status[0] & TINYMT64_MASK) as u128 | (status[1] as u128) << 64
+}
+
+/// Unpack the 127-bit internal state packed by `state_to_bits()`.
+fn bits_to_state(bits: u128) -> [u64; 2] {
+  [bits as u64, (bits >> 64) as u64]
+}
+
+/// The transition matrix of `tinymt64_next_state()` for the parameters of the specified tinymt.
+fn transition_matrix(random: &TinyMT64) -> Matrix {
+  Matrix::from_fn(|bits| {
+    let mut work = TinyMT64 { status: bits_to_state(bits), ..*random };
+    tinymt64_next_state(&mut work);
+    state_to_bits(&work.status)
+  })
+}
+
+/// This function determines whether the parameters of the tinymt give the maximal period
+/// 2^127-1 for every non-zero internal state.
+pub(crate) fn tinymt64_has_maximal_period(random: &TinyMT64) -> bool {
+  transition_matrix(random).has_maximal_order(state_to_bits(&[u64::MAX; 2]))
+}
+
+/// This function changes the internal state of tinymt64 to the one after the specified number
+/// of steps, that is, the same as calling `tinymt64_next_state()` `steps` times, but in
+/// O(log steps) time. This works for any parameter set.
+/// @param steps the number of steps to jump ahead.
+pub fn tinymt64_jump(random: &mut TinyMT64, steps: u128) {
+  if steps == 0 {
+    return;
+  }
+  // The MSB of status[0] doesn't affect the following states, but it is used for tempering, so
+  // the last step must be taken by the actual tinymt64_next_state().
+  let bits = transition_matrix(random).apply_pow(state_to_bits(&random.status), steps - 1);
+  random.status = bits_to_state(bits);
+  tinymt64_next_state(random);
+}
+
+/// This function returns the internal states of tinymt64 after 0, `stride`, 2 `stride`, ... steps,
+/// that is, the starting states of consecutive substreams of `stride` outputs. The jump matrix is
+/// computed only once, so each state takes O(1) time.
+/// @param stride the number of steps between the states.
+/// @return the iterator over the states, which never ends.
+///
+/// # Panics
+/// If `stride` is 0.
+pub fn tinymt64_jump_states(random: &TinyMT64, stride: u128) -> impl Iterator<Item = TinyMT64> {
+  assert!(stride != 0, "the stride must not be 0");
+  let jump = transition_matrix(random).pow(stride - 1);
+  let mut next = *random;
+  core::iter::from_fn(move || {
+    let current = next;
+    next.status = bits_to_state(jump.apply(state_to_bits(&next.status)));
+    tinymt64_next_state(&mut next);
+    Some(current)
+  })
+}
@@ -0,0 +1,138 @@
+extern crate tinymt;
+
+use tinymt::tinymt32::*;
+use tinymt::tinymt64::*;
+use tinymt::{BuildError, TinyMT, TinyMT32, TinyMT64};
+
+#[test]
+fn test_tinymt32_builder() {
+  // the parameter set and seed of check32.c
+  let mut expected = TinyMT32::new([0, 0, 0, 0], 0x8f7011ee, 0xfc78ff1f, 0x3793fdff);
+  tinymt32_init(&mut expected, 1);
+  assert_eq!(Ok(expected), TinyMT32::builder().seed(1).build());
+  assert_eq!(Ok(expected), TinyMT32::builder().param_id(0).seed(1).build());
+  assert_eq!(
+    Ok(expected),
+    TinyMT32::builder().params(0x8f7011ee, 0xfc78ff1f, 0x3793fdff).seed(1).build()
+  );
+
+  tinymt32_init_by_array(&mut expected, &[1]);
+  assert_eq!(Ok(expected), TinyMT32::builder().seed_array(&[1]).build());
+
+  for _ in 0..1000 {
+    tinymt32_generate_uint32(&mut expected);
+  }
+  assert_eq!(Ok(expected), TinyMT32::builder().seed_array(&[1]).skip(1000).build());
+  assert_eq!(Ok(expected), TinyMT32::builder().seed_array(&[1]).skip(400).jump(600).build());
+}
+
+#[test]
+fn test_tinymt64_builder() {
+  // the parameter set and seed of check64.c
+  let mut expected = TinyMT64::new([0, 0], 0xfa051f40, 0xffd0fff4, 0x58d02ffeffbfffbc);
+  tinymt64_init(&mut expected, 1);
+  assert_eq!(Ok(expected), TinyMT64::builder().seed(1).build());
+  assert_eq!(Ok(expected), TinyMT64::builder().param_id(0).seed(1).build());
+  assert_eq!(
+    Ok(expected),
+    TinyMT64::builder().params(0xfa051f40, 0xffd0fff4, 0x58d02ffeffbfffbc).seed(1).build()
+  );
+
+  tinymt64_init_by_array(&mut expected, &[1]);
+  assert_eq!(Ok(expected), TinyMT64::builder().seed_array(&[1]).build());
+
+  for _ in 0..1000 {
+    tinymt64_generate_uint64(&mut expected);
+  }
+  assert_eq!(Ok(expected), TinyMT64::builder().seed_array(&[1]).skip(1000).build());
+  assert_eq!(Ok(expected), TinyMT64::builder().seed_array(&[1]).skip(400).jump(600).build());
+}
+
+#[test]
+fn test_builder_errors() {
+  assert_eq!(Err(BuildError::MissingSeed), TinyMT32::builder().build());
+  assert_eq!(
+    Err(BuildError::ConflictingSeeds),
+    TinyMT32::builder().seed(1).seed_array(&[1]).build()
+  );
+  assert_eq!(
+    Err(BuildError::ConflictingParams),
+    TinyMT32::builder().param_id(0).params(1, 2, 3).seed(1).build()
+  );
+  assert_eq!(
+    Err(BuildError::UnknownParamId(u32::MAX)),
+    TinyMT32::builder().param_id(u32::MAX).seed(1).build()
+  );
+  assert_eq!(
+    Err(BuildError::DegenerateParams),
+    TinyMT32::builder().params(0, 0, 0).seed(1).build()
+  );
+
+  assert_eq!(Err(BuildError::MissingSeed), TinyMT64::builder().build());
+  assert_eq!(
+    Err(BuildError::ConflictingSeeds),
+    TinyMT64::builder().seed(1).seed_array(&[1]).build()
+  );
+  assert_eq!(
+    Err(BuildError::ConflictingParams),
+    TinyMT64::builder().param_id(0).params(1, 2, 3).seed(1).build()
+  );
+  assert_eq!(
+    Err(BuildError::UnknownParamId(u32::MAX)),
+    TinyMT64::builder().param_id(u32::MAX).seed(1).build()
+  );
+  assert_eq!(
+    Err(BuildError::DegenerateParams),
+    TinyMT64::builder().params(0, 0, 0).seed(1).build()
+  );
+}
+
+#[test]
+fn test_bundled_params() {
+  // every bundled parameter set must give the maximal period, which params() checks
+  let mut id = 0;
+  while let Ok(random) = TinyMT32::builder().param_id(id).seed(1).build() {
+    let (mat1, mat2, tmat) = random.params();
+    assert_eq!(Ok(random), TinyMT32::builder().params(mat1, mat2, tmat).seed(1).build(), "{}", id);
+    id += 1;
+  }
+  assert_eq!(Err(BuildError::UnknownParamId(id)), TinyMT32::builder().param_id(id).seed(1).build());
+
+  let mut id = 0;
+  while let Ok(random) = TinyMT64::builder().param_id(id).seed(1).build() {
+    let (mat1, mat2, tmat) = random.params();
+    assert_eq!(Ok(random), TinyMT64::builder().params(mat1, mat2, tmat).seed(1).build(), "{}", id);
+    id += 1;
+  }
+  assert_eq!(Err(BuildError::UnknownParamId(id)), TinyMT64::builder().param_id(id).seed(1).build());
+}
+
+#[test]
+fn test_jump() {
+  // jump must be equivalent to stepping one by one for any parameter set, even a degenerate one
+  let mut tinymt32 = TinyMT32::from_seed_u32(1234567890);
+  let mut tinymt64 = TinyMT64::from_seed_u64(12345678901234);
+  for steps in [0, 1, 2, 3, 127, 128, 1000, 4321] {
+    let mut expected32 = tinymt32;
+    let mut expected64 = tinymt64;
+    for _ in 0..steps {
+      tinymt32_next_state(&mut expected32);
+      tinymt64_next_state(&mut expected64);
+    }
+    tinymt32_jump(&mut tinymt32, steps);
+    tinymt64_jump(&mut tinymt64, steps);
+    assert_eq!(expected32, tinymt32);
+    assert_eq!(expected64, tinymt64);
+    assert_eq!(expected32.next_u32(), tinymt32.next_u32());
+    assert_eq!(expected64.next_u64(), tinymt64.next_u64());
+  }
+
+  // jumping by a sum of steps equals jumping by each of them
+  let mut a = TinyMT64::builder().seed(1).build().unwrap();
+  let mut b = a;
+  tinymt64_jump(&mut a, (1 << 100) + (1 << 64) + 12345);
+  tinymt64_jump(&mut b, 1 << 100);
+  tinymt64_jump(&mut b, 1 << 64);
+  tinymt64_jump(&mut b, 12345);
+  assert_eq!(a, b);
+}
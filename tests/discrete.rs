@@ -0,0 +1,225 @@
+//! `test_golden_values()` and `test_alias_table()` pin the discrete samplers, which must not change
+//! in any 1.x release, to the outputs of `TinyMT64` with the parameter set of check64.c.
+#![cfg(feature = "distributions")]
+extern crate tinymt;
+
+use tinymt::distributions::*;
+use tinymt::{TinyMT32, TinyMT64};
+
+/// The first `N` samples from a generator seeded with 1 and the parameter set id 0.
+fn samples<T, D: Distribution<T>, const N: usize>(distribution: D) -> [T; N] {
+  let mut random = TinyMT64::builder().seed(1).build().unwrap();
+  [(); N].map(|_| distribution.sample(&mut random))
+}
+
+#[test]
+fn test_golden_values() {
+  assert_eq!(
+    [false, false, true, false, false, false, true, false],
+    samples(Bernoulli::new(0.3).unwrap())
+  );
+  assert_eq!(
+    [false, false, true, false, false, false, true, false],
+    samples(Bernoulli::from_ratio(1, 3).unwrap())
+  );
+  assert_eq!([8, 9, 4, 6, 6, 7, 5, 7], samples(Binomial::new(20, 0.3).unwrap()));
+  assert_eq!([711, 699, 706, 702, 690, 708, 684, 682], samples(Binomial::new(1000, 0.7).unwrap()));
+  assert_eq!([4, 4, 2, 2, 1, 5, 8, 3], samples(Poisson::new(3.5).unwrap()));
+  assert_eq!([86, 99, 93, 87, 104, 78, 97, 104], samples(Poisson::new(100.0).unwrap()));
+  assert_eq!([0, 0, 9, 2, 3, 1, 6, 2], samples(Geometric::new(0.2).unwrap()));
+  assert_eq!([10, 9, 14, 12, 12, 11, 13, 11], samples(Hypergeometric::new(50, 20, 30).unwrap()));
+  assert_eq!(
+    [67, 65, 59, 62, 64, 56, 66, 60],
+    samples(Hypergeometric::new(1000, 600, 100).unwrap())
+  );
+}
+
+/// The natural logarithm of the binomial coefficient.
+fn ln_choose(n: u64, k: u64) -> f64 {
+  (0..k).map(|i| ((n - i) as f64).ln() - ((i + 1) as f64).ln()).sum()
+}
+
+/// Compare the histogram of `n` samples with the probability mass function by the chi-squared
+/// statistic over the values with an expected count of at least 5.
+fn check_pmf<D: Distribution<u64>, R: RandomSource>(
+  d: &D,
+  random: &mut R,
+  n: usize,
+  pmf: impl Fn(u64) -> f64,
+) {
+  let mut histogram = Vec::new();
+  for _ in 0..n {
+    let x = d.sample(random) as usize;
+    if x >= histogram.len() {
+      histogram.resize(x + 1, 0);
+    }
+    histogram[x] += 1;
+  }
+  let (mut chi2, mut df) = (0.0, 0);
+  for (x, observed) in histogram.iter().enumerate() {
+    let expected = pmf(x as u64) * n as f64;
+    if expected >= 5.0 {
+      chi2 += (*observed as f64 - expected).powi(2) / expected;
+      df += 1;
+    }
+  }
+  // about 4 standard deviations above the mean of the chi-squared distribution
+  let limit = df as f64 + 4.0 * (2.0 * df as f64).sqrt();
+  assert!(chi2 < limit, "chi2 = {} with {} degrees of freedom", chi2, df);
+}
+
+#[test]
+fn test_pmf() {
+  let binomial = |n: u64, p: f64| {
+    move |k: u64| {
+      if k > n {
+        0.0
+      } else {
+        (ln_choose(n, k) + k as f64 * p.ln() + (n - k) as f64 * (1.0 - p).ln()).exp()
+      }
+    }
+  };
+  let poisson = |lambda: f64| {
+    move |k: u64| {
+      (k as f64 * lambda.ln() - lambda - (1..=k).map(|i| (i as f64).ln()).sum::<f64>()).exp()
+    }
+  };
+  let hypergeometric = |population: u64, successes: u64, draws: u64| {
+    move |k: u64| {
+      if k > successes || k > draws || draws - k > population - successes {
+        0.0
+      } else {
+        (ln_choose(successes, k) + ln_choose(population - successes, draws - k)
+          - ln_choose(population, draws))
+        .exp()
+      }
+    }
+  };
+  let mut random32 = TinyMT32::from_seed_u32(1234567890);
+  let mut random64 = TinyMT64::from_seed_u64(12345678901234);
+  macro_rules! check {
+    ($d:expr, $pmf:expr) => {
+      check_pmf(&$d, &mut random32, 200000, $pmf);
+      check_pmf(&$d, &mut random64, 200000, $pmf);
+    };
+  }
+  check!(Binomial::new(20, 0.3).unwrap(), binomial(20, 0.3));
+  check!(Binomial::new(100, 0.95).unwrap(), binomial(100, 0.95));
+  check!(Binomial::new(60, 0.3).unwrap(), binomial(60, 0.3));
+  check!(Binomial::new(1000, 0.7).unwrap(), binomial(1000, 0.7));
+  check!(Binomial::new(5000, 0.4).unwrap(), binomial(5000, 0.4));
+  check!(Poisson::new(0.5).unwrap(), poisson(0.5));
+  check!(Poisson::new(9.5).unwrap(), poisson(9.5));
+  check!(Poisson::new(10.0).unwrap(), poisson(10.0));
+  check!(Poisson::new(250.0).unwrap(), poisson(250.0));
+  check!(Geometric::new(0.2).unwrap(), |k: u64| 0.2 * 0.8f64.powi(k as i32));
+  check!(Hypergeometric::new(50, 20, 30).unwrap(), hypergeometric(50, 20, 30));
+  check!(Hypergeometric::new(1000, 600, 100).unwrap(), hypergeometric(1000, 600, 100));
+  check!(Hypergeometric::new(2000, 1200, 1500).unwrap(), hypergeometric(2000, 1200, 1500));
+}
+
+#[test]
+fn test_hypergeometric_large_population() {
+  // the time of a sample doesn't grow with the population
+  const N: u64 = 1_000_000_000_000;
+  let mut random = TinyMT64::from_seed_u64(1);
+  for (successes, draws) in [(400_000_000_000, 500_000_000_000), (3_000_000, 2_000_000), (N / 2, 3)]
+  {
+    let hypergeometric = Hypergeometric::new(N, successes, draws).unwrap();
+    let p = successes as f64 / N as f64;
+    let mean = draws as f64 * p;
+    let variance = mean * (1.0 - p) * (N - draws) as f64 / (N - 1) as f64;
+    let (mut sum, mut sum2) = (0.0, 0.0);
+    for _ in 0..10000 {
+      let x = hypergeometric.sample(&mut random);
+      assert!(x <= successes.min(draws));
+      let d = x as f64 - mean;
+      sum += d;
+      sum2 += d * d;
+    }
+    assert!((sum / 10000.0).abs() < 5.0 * (variance / 10000.0).sqrt(), "{}", sum);
+    assert!((sum2 / 10000.0 / variance - 1.0).abs() < 0.1, "{}", sum2);
+  }
+  let hypergeometric = Hypergeometric::new(N, 7, N - 10).unwrap();
+  assert!((0..100).all(|_| hypergeometric.sample(&mut random) == 7));
+}
+
+#[test]
+fn test_bernoulli_frequencies() {
+  let mut random = TinyMT32::from_seed_u32(1234567890);
+  let bernoulli = Bernoulli::new(0.25).unwrap();
+  let count = (0..100000).filter(|_| bernoulli.sample(&mut random)).count();
+  assert!((24500..25500).contains(&count), "{}", count);
+  let bernoulli = Bernoulli::from_ratio(2, 7).unwrap();
+  let count = (0..70000).filter(|_| bernoulli.sample(&mut random)).count();
+  assert!((19500..20500).contains(&count), "{}", count);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_alias_table() {
+  assert_eq!([3, 2, 1, 1, 3, 0, 3, 0], samples(AliasTable::new(&[1.0, 2.0, 3.0, 4.0]).unwrap()));
+
+  let mut random = TinyMT32::from_seed_u32(1234567890);
+  let weights = [0.5, 0.0, 2.0, 1.0, 1e-3, 6.5];
+  let table = AliasTable::new(&weights).unwrap();
+  let mut counts = [0usize; 6];
+  for _ in 0..100000 {
+    counts[table.sample(&mut random)] += 1;
+  }
+  assert_eq!(0, counts[1]);
+  for (count, weight) in counts.iter().zip(weights) {
+    let expected = 100000.0 * weight / 10.001;
+    assert!((*count as f64 - expected).abs() < 5.0 * expected.sqrt() + 1.0, "{:?}", counts);
+  }
+
+  for _ in 0..100 {
+    assert_eq!(0, AliasTable::new(&[3.0]).unwrap().sample(&mut random));
+    assert_eq!(2, AliasTable::new(&[0.0, 0.0, 1.0]).unwrap().sample(&mut random));
+  }
+
+  use DistributionError::InvalidWeights;
+  assert_eq!(Err(InvalidWeights), AliasTable::new(&[]));
+  assert_eq!(Err(InvalidWeights), AliasTable::new(&[0.0, 0.0]));
+  assert_eq!(Err(InvalidWeights), AliasTable::new(&[1.0, -1.0]));
+  assert_eq!(Err(InvalidWeights), AliasTable::new(&[1.0, f64::NAN]));
+  assert_eq!(Err(InvalidWeights), AliasTable::new(&[f64::MAX, f64::MAX]));
+}
+
+#[test]
+fn test_edge_cases() {
+  let mut random = TinyMT64::from_seed_u64(1);
+  for _ in 0..100 {
+    assert!(!Bernoulli::new(0.0).unwrap().sample(&mut random));
+    assert!(Bernoulli::new(1.0).unwrap().sample(&mut random));
+    assert!(!Bernoulli::from_ratio(0, 5).unwrap().sample(&mut random));
+    assert!(Bernoulli::from_ratio(5, 5).unwrap().sample(&mut random));
+    assert_eq!(0, Binomial::new(100, 0.0).unwrap().sample(&mut random));
+    assert_eq!(100, Binomial::new(100, 1.0).unwrap().sample(&mut random));
+    assert_eq!(0, Binomial::new(0, 0.5).unwrap().sample(&mut random));
+    assert!(Binomial::new(u64::MAX, 0.5).unwrap().sample(&mut random) > u64::MAX / 4);
+    assert_eq!(0, Geometric::new(1.0).unwrap().sample(&mut random));
+    assert_eq!(0, Hypergeometric::new(10, 0, 5).unwrap().sample(&mut random));
+    assert_eq!(5, Hypergeometric::new(10, 10, 5).unwrap().sample(&mut random));
+    assert_eq!(7, Hypergeometric::new(10, 7, 10).unwrap().sample(&mut random));
+    assert_eq!(0, Hypergeometric::new(0, 0, 0).unwrap().sample(&mut random));
+  }
+}
+
+#[test]
+fn test_invalid_parameters() {
+  use DistributionError::*;
+  assert_eq!(Err(NotProbability), Bernoulli::new(-0.1));
+  assert_eq!(Err(NotProbability), Bernoulli::new(1.5));
+  assert_eq!(Err(NotProbability), Bernoulli::new(f64::NAN));
+  assert_eq!(Err(InvalidCount), Bernoulli::from_ratio(1, 0));
+  assert_eq!(Err(InvalidCount), Bernoulli::from_ratio(4, 3));
+  assert_eq!(Err(NotProbability), Binomial::new(10, f64::NAN));
+  assert_eq!(Err(NotPositive), Poisson::new(0.0));
+  assert_eq!(Err(NotFinite), Poisson::new(f64::INFINITY));
+  assert_eq!(Err(NotProbability), Geometric::new(0.0));
+  assert_eq!(Err(NotFinite), Geometric::new(f64::NAN));
+  assert_eq!(Err(InvalidCount), Hypergeometric::new(10, 11, 5));
+  assert_eq!(Err(InvalidCount), Hypergeometric::new(10, 5, 11));
+  assert_eq!("a probability is not in [0, 1]", NotProbability.to_string());
+}
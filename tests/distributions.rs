@@ -0,0 +1,157 @@
+//! The golden values are frozen; a change of them breaks the reproducibility of the samples.
+//! They're generated with the parameter set id 0 of check64.c.
+#![cfg(feature = "distributions")]
+extern crate tinymt;
+
+use tinymt::distributions::*;
+use tinymt::{TinyMT32, TinyMT64};
+
+#[test]
+fn test_golden_values() {
+  fn samples<D: Distribution<f64>>(distribution: D) -> [f64; 4] {
+    let mut random = TinyMT64::builder().seed(1).build().unwrap();
+    [(); 4].map(|_| distribution.sample(&mut random))
+  }
+  assert_eq!(
+    [2.361851766374903, 2.7472070675194806, -0.527754830147344, 1.1874818817364425],
+    samples(Uniform::new(-1.0, 3.0).unwrap())
+  );
+  assert_eq!(
+    [1.2462917800476476, 1.4499554479721168, -2.047584596779307, 0.14519769484649175],
+    samples(Normal::new(0.0, 1.0).unwrap())
+  );
+  assert_eq!(
+    [3.4774239661011643, 4.262924589002898, 0.1290462261219125, 1.156268135925786],
+    samples(LogNormal::new(0.0, 1.0).unwrap())
+  );
+  assert_eq!(
+    [0.08690120900561943, 0.03264179031013678, 1.0682756808591933, 0.3017716522696642],
+    samples(Exponential::new(2.0).unwrap())
+  );
+  assert_eq!(
+    [4.567614125597826, 0.33423200889356725, 2.124929010790509, 1.00795215596541],
+    samples(Gamma::new(2.5, 1.0).unwrap())
+  );
+  assert_eq!(
+    [0.04316661615595428, 0.6639305167993542, 0.3769286942090581, 0.02887827493325917],
+    samples(Gamma::new(0.5, 1.0).unwrap())
+  );
+  assert_eq!(
+    [0.8798082332402529, 0.5469419724931496, 0.6677349334717512, 0.8339116308548871],
+    samples(Beta::new(2.0, 3.0).unwrap())
+  );
+  assert_eq!(
+    [1.8252763875893783, 4.970334031110787, -2.5713585384308493, 0.14832144440626963],
+    samples(Cauchy::new(0.0, 1.0).unwrap())
+  );
+  assert_eq!(
+    [0.31143724062892947, 0.16213221308828035, 1.6588567782108745, 0.7141766128904093],
+    samples(Weibull::new(1.0, 1.5).unwrap())
+  );
+}
+
+/// The sample mean and variance of `n` samples.
+fn moments<D: Distribution<f64>, R: RandomSource>(d: &D, random: &mut R, n: usize) -> (f64, f64) {
+  let (mut sum, mut sum2) = (0.0, 0.0);
+  for _ in 0..n {
+    let x = d.sample(random);
+    assert!(x.is_finite());
+    sum += x;
+    sum2 += x * x;
+  }
+  let mean = sum / n as f64;
+  (mean, sum2 / n as f64 - mean * mean)
+}
+
+#[test]
+fn test_moments() {
+  let gamma = |k: f64, theta: f64| (k * theta, k * theta * theta);
+  let e = core::f64::consts::E;
+  let mut random32 = TinyMT32::from_seed_u32(1234567890);
+  let mut random64 = TinyMT64::from_seed_u64(12345678901234);
+  macro_rules! check {
+    ($d:expr, $expected:expr) => {
+      let (mean, variance) = $expected;
+      for (actual_mean, actual_variance) in
+        [moments(&$d, &mut random32, 100000), moments(&$d, &mut random64, 100000)]
+      {
+        assert!(
+          (actual_mean - mean).abs() < 0.02 * (1.0 + mean.abs()),
+          "{} != {}",
+          actual_mean,
+          mean
+        );
+        assert!(
+          (actual_variance - variance).abs() < 0.05 * (1.0 + variance),
+          "{} != {}",
+          actual_variance,
+          variance
+        );
+      }
+    };
+  }
+  check!(Uniform::new(-1.0, 3.0).unwrap(), (1.0, 16.0 / 12.0));
+  check!(Normal::new(10.0, 2.0).unwrap(), (10.0, 4.0));
+  check!(LogNormal::new(0.0, 0.5).unwrap(), (e.powf(0.125), (e.powf(0.25) - 1.0) * e.powf(0.25)));
+  check!(Exponential::new(2.0).unwrap(), (0.5, 0.25));
+  check!(Gamma::new(2.5, 2.0).unwrap(), gamma(2.5, 2.0));
+  check!(Gamma::new(0.3, 1.0).unwrap(), gamma(0.3, 1.0));
+  check!(Beta::new(2.0, 3.0).unwrap(), (0.4, 6.0 / 150.0));
+  check!(Weibull::new(1.0, 1.0).unwrap(), (1.0, 1.0));
+}
+
+#[test]
+fn test_beta_small_shapes() {
+  // both samples of Gamma(1e-3) are often 0, which must not make 0 / 0
+  let mut random = TinyMT64::from_seed_u64(1);
+  for (alpha, beta, mean) in [(1e-3, 1e-3, 0.5), (1e-3, 2.0, 1e-3 / 2.001), (0.5, 0.5, 0.5)] {
+    let distribution = Beta::new(alpha, beta).unwrap();
+    let mut sum = 0.0;
+    for _ in 0..100000 {
+      let x = distribution.sample(&mut random);
+      assert!((0.0..=1.0).contains(&x), "{}", x);
+      sum += x;
+    }
+    assert!((sum / 100000.0 - mean).abs() < 0.01, "{} {}", alpha, sum);
+  }
+}
+
+#[test]
+fn test_normal_tail_and_cauchy_quantiles() {
+  // P(|X| > 3.654...) of N(0, 1) is about 2.58e-4, which is sampled from the tail of the ziggurat
+  let mut random = TinyMT64::from_seed_u64(1);
+  let normal = Normal::new(0.0, 1.0).unwrap();
+  let tail = (0..1000000).filter(|_| normal.sample(&mut random).abs() > 3.654152885361009).count();
+  assert!((200..320).contains(&tail), "{}", tail);
+
+  // the quartiles of the standard Cauchy distribution are -1 and 1
+  let cauchy = Cauchy::new(0.0, 1.0).unwrap();
+  let mut counts = [0; 4];
+  for _ in 0..100000 {
+    let x = cauchy.sample(&mut random);
+    counts[if x < -1.0 {
+      0
+    } else if x < 0.0 {
+      1
+    } else if x < 1.0 {
+      2
+    } else {
+      3
+    }] += 1;
+  }
+  assert!(counts.iter().all(|count| (24000..26000).contains(count)), "{:?}", counts);
+}
+
+#[test]
+fn test_invalid_parameters() {
+  assert_eq!(Err(DistributionError::EmptyInterval), Uniform::new(1.0, 1.0));
+  assert_eq!(Err(DistributionError::NotFinite), Uniform::new(f64::MIN, f64::MAX));
+  assert_eq!(Err(DistributionError::NotFinite), Normal::new(f64::NAN, 1.0));
+  assert_eq!(Err(DistributionError::NotPositive), Normal::new(0.0, 0.0));
+  assert_eq!(Err(DistributionError::NotPositive), LogNormal::new(0.0, -1.0));
+  assert_eq!(Err(DistributionError::NotFinite), Exponential::new(f64::INFINITY));
+  assert_eq!(Err(DistributionError::NotPositive), Gamma::new(0.0, 1.0));
+  assert_eq!(Err(DistributionError::NotPositive), Beta::new(1.0, -1.0));
+  assert_eq!(Err(DistributionError::NotPositive), Cauchy::new(0.0, 0.0));
+  assert_eq!(Err(DistributionError::NotFinite), Weibull::new(1.0, f64::NAN));
+}
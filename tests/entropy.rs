@@ -0,0 +1,17 @@
+#![cfg(feature = "getrandom")]
+extern crate tinymt;
+
+use tinymt::{TinyMT32, TinyMT64};
+
+#[test]
+fn test_from_os_entropy() {
+  let mut a = TinyMT64::from_os_entropy().unwrap();
+  let mut b = TinyMT64::from_os_entropy().unwrap();
+  assert_ne!(a, b);
+  assert_ne!([(); 4].map(|_| a.next_u64()), [(); 4].map(|_| b.next_u64()));
+
+  let mut a = TinyMT32::from_os_entropy().unwrap();
+  let mut b = TinyMT32::from_os_entropy().unwrap();
+  assert_ne!(a, b);
+  assert_ne!([(); 4].map(|_| a.next_u32()), [(); 4].map(|_| b.next_u32()));
+}
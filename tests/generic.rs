@@ -0,0 +1,111 @@
+use tinymt::tinymt32::*;
+use tinymt::tinymt64::*;
+use tinymt::{TinyMT, TinyMT32, TinyMT32Seed, TinyMT64, TinyMT64Seed};
+
+/// Initialize a generator of the default parameters generically.
+fn from_seed<R: TinyMT>(seed: R::Word) -> R {
+  let mut random = R::from_params(R::DEFAULT_PARAMS);
+  random.init(seed);
+  random
+}
+
+/// Take one output of each method.
+fn outputs<R: TinyMT>(random: &mut R) -> (R::Word, [f32; 5], [f64; 4]) {
+  let word = random.next_word();
+  let f32s = [
+    random.next_f32(),
+    random.next_f32_12(),
+    random.next_f32_oc(),
+    random.next_f32_oo(),
+    random.next_f32_full(),
+  ];
+  let f64s =
+    [random.next_f64(), random.next_f64_oc(), random.next_f64_oo(), random.next_f64_full()];
+  (word, f32s, f64s)
+}
+
+#[test]
+fn test_tinymt32() {
+  let mut random: TinyMT32 = from_seed(1);
+  assert_eq!(Ok(random), TinyMT32::builder().seed(1).build());
+  assert_eq!(TinyMT32::DEFAULT_PARAMS, random.params());
+  assert_eq!(32, TinyMT32::WORD_BITS);
+  assert_eq!(TinyMT32Seed::from(1).0, <TinyMT32 as TinyMT>::Seed::from(1).0);
+
+  let mut expected = random;
+  let (word, f32s, f64s) = outputs(&mut random);
+  assert_eq!(tinymt32_generate_uint32(&mut expected), word);
+  assert_eq!(
+    [
+      tinymt32_generate_float(&mut expected),
+      tinymt32_generate_float12(&mut expected),
+      tinymt32_generate_float_oc(&mut expected),
+      tinymt32_generate_float_oo(&mut expected),
+      tinymt32_generate_float_full(&mut expected),
+    ],
+    f32s
+  );
+  assert_eq!(
+    [
+      tinymt32_generate_53double(&mut expected),
+      tinymt32_generate_53double_oc(&mut expected),
+      tinymt32_generate_53double_oo(&mut expected),
+      tinymt32_generate_double_full(&mut expected),
+    ],
+    f64s
+  );
+  assert_eq!(expected, random);
+
+  random.init_by_array(&[1, 2, 3]);
+  random.jump(1000);
+  random.next_state();
+  assert_eq!(Ok(random), TinyMT32::builder().seed_array(&[1, 2, 3]).jump(1001).build());
+}
+
+#[test]
+fn test_tinymt64() {
+  let mut random: TinyMT64 = from_seed(1);
+  assert_eq!(Ok(random), TinyMT64::builder().seed(1).build());
+  assert_eq!(TinyMT64::DEFAULT_PARAMS, random.params());
+  assert_eq!(64, TinyMT64::WORD_BITS);
+  assert_eq!(TinyMT64Seed::from(1).0, <TinyMT64 as TinyMT>::Seed::from(1).0);
+
+  let mut expected = random;
+  let (word, f32s, f64s) = outputs(&mut random);
+  assert_eq!(tinymt64_generate_uint64(&mut expected), word);
+  assert_eq!(
+    [
+      tinymt64_generate_float(&mut expected),
+      tinymt64_generate_float12(&mut expected),
+      tinymt64_generate_float_oc(&mut expected),
+      tinymt64_generate_float_oo(&mut expected),
+      tinymt64_generate_float_full(&mut expected),
+    ],
+    f32s
+  );
+  assert_eq!(
+    [
+      tinymt64_generate_double(&mut expected),
+      tinymt64_generate_double_oc(&mut expected),
+      tinymt64_generate_double_oo(&mut expected),
+      tinymt64_generate_double_full(&mut expected),
+    ],
+    f64s
+  );
+  assert_eq!(expected, random);
+
+  random.init_by_array(&[1, 2, 3]);
+  random.jump(1000);
+  random.next_state();
+  assert_eq!(Ok(random), TinyMT64::builder().seed_array(&[1, 2, 3]).jump(1001).build());
+}
+
+#[test]
+fn test_from_params() {
+  let random = TinyMT64::from_params((1, 2, 3));
+  assert_eq!(TinyMT64::new([0, 0], 1, 2, 3), random);
+  assert_eq!((1, 2, 3), random.params());
+  let random = TinyMT32::from_params((1, 2, 3));
+  assert_eq!(TinyMT32::new([0, 0, 0, 0], 1, 2, 3), random);
+  assert_eq!((1, 2, 3), random.params());
+}
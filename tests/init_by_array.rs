@@ -0,0 +1,67 @@
+extern crate tinymt;
+
+use std::collections::HashSet;
+
+use tinymt::tinymt32::*;
+use tinymt::tinymt64::*;
+use tinymt::{TinyMT32, TinyMT64};
+
+/// The longest key length examined exhaustively.
+const MAX_KEY_LENGTH: usize = 1000;
+
+/// Keys of every length in 0..=MAX_KEY_LENGTH filled with zeros, the maximum value and random
+/// values. The maximum value makes every `r + key + i` in the initialization overflow.
+fn keys<T: Copy>(zero: T, max: T, mut random: impl FnMut() -> T) -> Vec<Vec<T>> {
+  let mut keys = Vec::new();
+  for length in 0..=MAX_KEY_LENGTH {
+    keys.push(vec![zero; length]);
+    keys.push(vec![max; length]);
+    keys.push((0..length).map(|_| random()).collect());
+  }
+  keys
+}
+
+#[test]
+fn test_tinymt32_init_by_array_for_any_key() {
+  let mut source = TinyMT32::from_seed_u32(1);
+  let keys = keys(0, u32::MAX, || source.next_u32());
+  // the reference parameters, the parameters used by `from_seed_u32()` and all-one parameters
+  for (mat1, mat2, tmat) in [(0x8f7011ee, 0xfc78ff1f, 0x3793fdff), (0, 0, 0), (!0, !0, !0)] {
+    let mut states = HashSet::new();
+    for key in &keys {
+      let mut random = TinyMT32::new([0, 0, 0, 0], mat1, mat2, tmat);
+      tinymt32_init_by_array(&mut random, key);
+      // the same key gives the same state
+      let mut again = TinyMT32::new([0, 0, 0, 0], mat1, mat2, tmat);
+      tinymt32_init_by_array(&mut again, key);
+      assert_eq!(random, again);
+      // the state is never all zero, which would only output zeros
+      let outputs = [(); 4].map(|_| tinymt32_generate_uint32(&mut random));
+      assert_ne!([0; 4], outputs);
+      states.insert(outputs);
+    }
+    // every key gives a distinct state, except for the three empty keys
+    assert_eq!(keys.len() - 2, states.len());
+  }
+}
+
+#[test]
+fn test_tinymt64_init_by_array_for_any_key() {
+  let mut source = TinyMT64::from_seed_u64(1);
+  let keys = keys(0, u64::MAX, || source.next_u64());
+  for (mat1, mat2, tmat) in [(0xfa051f40, 0xffd0fff4, 0x58d02ffeffbfffbc), (0, 0, 0), (!0, !0, !0)]
+  {
+    let mut states = HashSet::new();
+    for key in &keys {
+      let mut random = TinyMT64::new([0, 0], mat1, mat2, tmat);
+      tinymt64_init_by_array(&mut random, key);
+      let mut again = TinyMT64::new([0, 0], mat1, mat2, tmat);
+      tinymt64_init_by_array(&mut again, key);
+      assert_eq!(random, again);
+      let outputs = [(); 2].map(|_| tinymt64_generate_uint64(&mut random));
+      assert_ne!([0; 2], outputs);
+      states.insert(outputs);
+    }
+    assert_eq!(keys.len() - 2, states.len());
+  }
+}
@@ -0,0 +1,71 @@
+use tinymt::tinymt32::*;
+use tinymt::tinymt64::*;
+use tinymt::{TinyMT32, TinyMT64};
+
+#[test]
+fn test_iter_tinymt32() {
+  let mut random = TinyMT32::from_seed_u32(1);
+  let mut expected = random;
+  let values = random.iter_u32().take(100).collect::<Vec<_>>();
+  assert!(values.iter().all(|x| *x == tinymt32_generate_uint32(&mut expected)));
+  assert_eq!(expected, random);
+
+  assert!(random.iter_f32().take(100).all(|x| x == tinymt32_generate_float(&mut expected)));
+  assert!(random.iter_f32_12().take(100).all(|x| x == tinymt32_generate_float12(&mut expected)));
+  assert!(random.iter_f32_oc().take(100).all(|x| x == tinymt32_generate_float_oc(&mut expected)));
+  assert!(random.iter_f32_oo().take(100).all(|x| x == tinymt32_generate_float_oo(&mut expected)));
+  assert!(random.iter_f64().take(100).all(|x| x == tinymt32_generate_53double(&mut expected)));
+  assert!(random
+    .iter_f64_oc()
+    .take(100)
+    .all(|x| x == tinymt32_generate_53double_oc(&mut expected)));
+  assert!(random
+    .iter_f64_oo()
+    .take(100)
+    .all(|x| x == tinymt32_generate_53double_oo(&mut expected)));
+  assert_eq!(expected, random);
+}
+
+#[test]
+fn test_iter_tinymt64() {
+  let mut random = TinyMT64::from_seed_u64(1);
+  let mut expected = random;
+  let values = random.iter_u64().take(100).collect::<Vec<_>>();
+  assert!(values.iter().all(|x| *x == tinymt64_generate_uint64(&mut expected)));
+  assert_eq!(expected, random);
+
+  assert!(random.iter_f64().take(100).all(|x| x == tinymt64_generate_double(&mut expected)));
+  assert!(random.iter_f64_12().take(100).all(|x| x == tinymt64_generate_double12(&mut expected)));
+  assert!(random.iter_f64_oc().take(100).all(|x| x == tinymt64_generate_double_oc(&mut expected)));
+  assert!(random.iter_f64_oo().take(100).all(|x| x == tinymt64_generate_double_oo(&mut expected)));
+  assert!(random.iter_f32().take(100).all(|x| x == tinymt64_generate_float(&mut expected)));
+  assert!(random.iter_f32_12().take(100).all(|x| x == tinymt64_generate_float12(&mut expected)));
+  assert!(random.iter_f32_oc().take(100).all(|x| x == tinymt64_generate_float_oc(&mut expected)));
+  assert!(random.iter_f32_oo().take(100).all(|x| x == tinymt64_generate_float_oo(&mut expected)));
+  assert_eq!(expected, random);
+}
+
+#[test]
+fn test_iter_composition() {
+  let mut random = TinyMT64::from_seed_u64(1);
+  let mut expected = random;
+  let mut iter = random.iter_u64();
+  assert_eq!((usize::MAX, None), iter.size_hint());
+
+  // by_ref() takes from the same stream without consuming the iterator
+  let head = iter.by_ref().take(3).collect::<Vec<_>>();
+  let odd = iter.by_ref().step_by(2).take(3).collect::<Vec<_>>();
+  let next = iter.next();
+  assert_eq!([(); 3].map(|_| expected.next_u64()).to_vec(), head);
+  let skipped = [(); 5].map(|_| expected.next_u64());
+  assert_eq!(vec![skipped[0], skipped[2], skipped[4]], odd);
+  assert_eq!(Some(expected.next_u64()), next);
+  assert_eq!(expected, random);
+
+  let mut random = TinyMT32::from_seed_u32(1);
+  let mut expected = random;
+  // zip() polls the range first so that no output is taken after it ends
+  let sum = (1..=10).zip(random.iter_f64()).map(|(i, x)| x * i as f64).sum::<f64>();
+  assert_eq!((1..=10).map(|i| expected.next_f64() * i as f64).sum::<f64>(), sum);
+  assert_eq!(expected, random);
+}
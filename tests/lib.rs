@@ -1,9 +1,27 @@
 extern crate tinymt;
 
+#[cfg(feature = "rand")]
 use rand::{Rng, RngCore, SeedableRng};
 
-use tinymt::{TinyMT32, TinyMT32Seed, TinyMT64, TinyMT64Seed};
+#[cfg(feature = "rand")]
+use tinymt::{TinyMT32, TinyMT64};
+use tinymt::{TinyMT32Seed, TinyMT64Seed};
 
+pub mod builder;
+pub mod discrete;
+pub mod distributions;
+pub mod entropy;
+pub mod generic;
+pub mod init_by_array;
+pub mod iter;
+pub mod parallel;
+pub mod rand_core;
+pub mod range;
+pub mod reader;
+pub mod seq;
+pub mod shared;
+pub mod sync;
+pub mod thread;
 pub mod tinymt32;
 pub mod tinymt64;
 
@@ -22,25 +40,50 @@ fn test_tinymt32_seed() {
   assert_eq!([0x04, 0x03, 0x02, 0x01], TinyMT32Seed::from(0x01020304u32).as_mut());
 }
 
+/// The inherent methods, available without the `rand` feature, are the same as those of `RngCore`.
 #[test]
+#[cfg(feature = "rand")]
+fn test_inherent_methods_match_rng_core() {
+  let (mut a, mut b) = (TinyMT64::from_seed_u64(1), TinyMT64::from_seed_u64(1));
+  let (mut c, mut d) = (TinyMT32::from_seed_u32(1), TinyMT32::from_seed_u32(1));
+  for size in 0..20 {
+    assert_eq!(a.next_u32(), RngCore::next_u32(&mut b));
+    assert_eq!(a.next_u64(), RngCore::next_u64(&mut b));
+    assert_eq!(c.next_u32(), RngCore::next_u32(&mut d));
+    assert_eq!(c.next_u64(), RngCore::next_u64(&mut d));
+    let (mut x, mut y) = (vec![0u8; size], vec![0u8; size]);
+    a.fill_bytes(&mut x);
+    RngCore::fill_bytes(&mut b, &mut y);
+    assert_eq!(x, y);
+    c.fill_bytes(&mut x);
+    RngCore::fill_bytes(&mut d, &mut y);
+    assert_eq!(x, y);
+  }
+}
+
+#[test]
+#[cfg(feature = "rand")]
 fn test_chi_squared_tinymt64() {
   let mut random = TinyMT64::from_seed(TinyMT64Seed::from(12345678901234u64));
   test_chi_squared(&mut random);
 }
 
 #[test]
+#[cfg(feature = "rand")]
 fn test_chi_squared_tinymt32() {
   let mut random = TinyMT32::from_seed(TinyMT32Seed::from(1234567890u32));
   test_chi_squared(&mut random);
 }
 
 #[test]
+#[cfg(feature = "rand")]
 fn test_try_fill_bytes_tinymt64() {
   let mut random = TinyMT64::from_seed(TinyMT64Seed::from(12345678901234u64));
   test_try_fill_bytes(&mut random);
 }
 
 #[test]
+#[cfg(feature = "rand")]
 fn test_try_fill_bytes_tinymt32() {
   let mut random = TinyMT32::from_seed(TinyMT32Seed::from(1234567890u32));
   test_try_fill_bytes(&mut random);
@@ -48,6 +91,7 @@ fn test_try_fill_bytes_tinymt32() {
 
 /// Test that the significance level of the chi-square test for random number sequence generated
 /// by the specified PRING is 95% or higher.
+#[cfg(feature = "rand")]
 fn test_chi_squared(random: &mut dyn RngCore) {
   const DEGREE_OF_FREEDOM: usize = 9;
   const THRESHOLD: f64 = 16.92; // 5% for 9 degrees of freedom
@@ -62,6 +106,7 @@ fn test_chi_squared(random: &mut dyn RngCore) {
 }
 
 /// Acquire random bytes using various buffer lengths and perform a 0.5% chi-square test.
+#[cfg(feature = "rand")]
 fn test_try_fill_bytes(random: &mut dyn RngCore) {
   const SAMPLING_COUNT: usize = 200000;
   const THRESHOLD: f64 = 20.3; // 0.5% for 7 degree of freedom
@@ -85,6 +130,7 @@ fn test_try_fill_bytes(random: &mut dyn RngCore) {
 }
 
 /// Performs a chi-square test using the specified histogram of uniform random numbers.
+#[cfg(feature = "rand")]
 fn verify_chi_squared(histogram: &[u32], expected: f64, threshold: f64) {
   let mut chi2: f64 = 0f64;
   for (i, h) in histogram.iter().enumerate() {
@@ -97,7 +143,7 @@ fn verify_chi_squared(histogram: &[u32], expected: f64, threshold: f64) {
 }
 
 #[test]
-#[cfg(not(any(target_family = "windows", target_family = "wasm")))]
+#[cfg(all(feature = "rand", not(any(target_family = "windows", target_family = "wasm"))))]
 fn profiling() {
   use pprof;
   use rand::Rng;
@@ -0,0 +1,56 @@
+#![cfg(feature = "rayon")]
+extern crate tinymt;
+
+use tinymt::tinymt64::tinymt64_jump;
+use tinymt::{par_fill_u64, ParallelTinyMT64, TinyMT64};
+
+/// Run `f` in a rayon thread pool of `threads` threads.
+fn with_threads<T: Send, F: FnOnce() -> T + Send>(threads: usize, f: F) -> T {
+  rayon::ThreadPoolBuilder::new().num_threads(threads).build().unwrap().install(f)
+}
+
+#[test]
+fn test_par_fill_u64_matches_sequential_fill() {
+  for len in [0, 1, 1000, (1 << 16) - 1, 1 << 16, (1 << 16) + 1, 300_000] {
+    let mut random = TinyMT64::builder().seed(42).build().unwrap();
+    let expected = (0..len).map(|_| random.next_u64()).collect::<Vec<_>>();
+    for threads in [1, 3, 8] {
+      let mut values = vec![0u64; len];
+      with_threads(threads, || par_fill_u64(&mut values, 42));
+      assert_eq!(expected, values, "len = {}, threads = {}", len, threads);
+    }
+  }
+}
+
+#[test]
+fn test_consecutive_fills_continue_stream() {
+  let mut random = TinyMT64::builder().seed(1).build().unwrap();
+  let mut parallel = ParallelTinyMT64::with_chunk_len(random, 7);
+  for len in [0, 1, 6, 7, 8, 100, 1001] {
+    let expected = (0..len).map(|_| random.next_u64()).collect::<Vec<_>>();
+    let mut values = vec![0u64; len];
+    parallel.fill_u64(&mut values);
+    assert_eq!(expected, values);
+    assert_eq!(&random, parallel.random());
+  }
+}
+
+#[test]
+fn test_for_each_chunk_uses_jumped_substreams() {
+  let base = TinyMT64::builder().seed(1).build().unwrap();
+  let parallel = ParallelTinyMT64::with_chunk_len(base, 10);
+  let run = |threads| {
+    let mut values = vec![0u64; 95];
+    with_threads(threads, || {
+      parallel.for_each_chunk(&mut values, |random, chunk| chunk.fill_with(|| random.next_u64()))
+    });
+    values
+  };
+  let values = run(1);
+  assert_eq!(values, run(4));
+  for (i, chunk) in values.chunks(10).enumerate() {
+    let mut random = base;
+    tinymt64_jump(&mut random, i as u128 * ParallelTinyMT64::CHUNK_STRIDE);
+    assert!(chunk.iter().all(|x| *x == random.next_u64()));
+  }
+}
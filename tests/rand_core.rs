@@ -0,0 +1,116 @@
+//! The implementations of the traits of `rand_core` give the same streams as the generators.
+#![cfg(feature = "rand_core_06")]
+extern crate tinymt;
+
+use tinymt::{TinyMT32, TinyMT64};
+
+#[test]
+#[cfg(feature = "rand_core_09")]
+fn test_same_streams_across_generations() {
+  use rand_core_06::{RngCore as RngCore06, SeedableRng as SeedableRng06};
+  use rand_core_09::{RngCore as RngCore09, SeedableRng as SeedableRng09, TryRngCore};
+  use tinymt::{TinyMT32Seed, TinyMT64Seed};
+
+  for seed in [0u64, 1, 12345678901234, u64::MAX] {
+    let mut a = <TinyMT64 as SeedableRng06>::from_seed(TinyMT64Seed::from(seed));
+    let mut b = <TinyMT64 as SeedableRng09>::from_seed(TinyMT64Seed::from(seed));
+    assert_eq!(a, b);
+    assert_eq!(
+      <TinyMT64 as SeedableRng06>::seed_from_u64(seed),
+      <TinyMT64 as SeedableRng09>::seed_from_u64(seed)
+    );
+    let mut c = <TinyMT32 as SeedableRng06>::from_seed(TinyMT32Seed::from(seed as u32));
+    let mut d = <TinyMT32 as SeedableRng09>::from_seed(TinyMT32Seed::from(seed as u32));
+    assert_eq!(c, d);
+    assert_eq!(
+      <TinyMT32 as SeedableRng06>::seed_from_u64(seed),
+      <TinyMT32 as SeedableRng09>::seed_from_u64(seed)
+    );
+
+    for size in 0..20 {
+      assert_eq!(RngCore06::next_u32(&mut a), RngCore09::next_u32(&mut b));
+      assert_eq!(RngCore06::next_u64(&mut a), RngCore09::next_u64(&mut b));
+      assert_eq!(RngCore06::next_u32(&mut c), RngCore09::next_u32(&mut d));
+      assert_eq!(RngCore06::next_u64(&mut c), RngCore09::next_u64(&mut d));
+      let (mut x, mut y) = (vec![0u8; size], vec![0u8; size]);
+      RngCore06::try_fill_bytes(&mut a, &mut x).unwrap();
+      TryRngCore::try_fill_bytes(&mut b, &mut y).unwrap();
+      assert_eq!(x, y);
+      RngCore06::fill_bytes(&mut c, &mut x);
+      RngCore09::fill_bytes(&mut d, &mut y);
+      assert_eq!(x, y);
+    }
+  }
+}
+
+#[test]
+fn test_block_rng_streams() {
+  use rand_core_06::{RngCore, SeedableRng};
+  use tinymt::{BlockTinyMT32, BlockTinyMT64};
+
+  let mut random = TinyMT32::from_seed_u32(1);
+  let mut block = BlockTinyMT32::new(TinyMT32::from_seed_u32(1));
+  for _ in 0..100 {
+    assert_eq!(random.next_u32(), block.next_u32());
+  }
+
+  let mut random = TinyMT64::from_seed_u64(1);
+  let mut block = BlockTinyMT64::new(TinyMT64::from_seed_u64(1));
+  for _ in 0..100 {
+    assert_eq!(random.next_u64(), block.next_u64());
+  }
+
+  // fill_bytes across the boundaries of the blocks
+  for size in [0, 1, 7, 8, 9, 127, 128, 129, 1000] {
+    let (mut x, mut y) = (vec![0u8; size], vec![0u8; size]);
+    let mut random = TinyMT32::seed_from_u64(2);
+    let mut block = BlockTinyMT32::seed_from_u64(2);
+    for _ in 0..3 {
+      random.fill_bytes(&mut x);
+      block.fill_bytes(&mut y);
+      assert_eq!(x, y);
+    }
+    let mut random = TinyMT64::seed_from_u64(2);
+    let mut block = BlockTinyMT64::seed_from_u64(2);
+    for _ in 0..3 {
+      random.fill_bytes(&mut x);
+      block.fill_bytes(&mut y);
+      assert_eq!(x, y);
+    }
+  }
+}
+
+/// Mixed calls of `next_u32()`, `next_u64()` and `fill_bytes()` give the same values as the
+/// unbuffered generators, also across the boundaries of the blocks.
+#[test]
+fn test_block_rng_mixed_calls() {
+  use rand_core_06::{RngCore, SeedableRng};
+  use tinymt::{BlockTinyMT32, BlockTinyMT64};
+
+  let mut random32 = TinyMT32::seed_from_u64(3);
+  let mut block32 = BlockTinyMT32::seed_from_u64(3);
+  let mut random64 = TinyMT64::seed_from_u64(3);
+  let mut block64 = BlockTinyMT64::seed_from_u64(3);
+  for i in 0..500usize {
+    match i % 5 {
+      0 | 3 => {
+        assert_eq!(random32.next_u32(), block32.next_u32());
+        assert_eq!(random64.next_u32(), block64.next_u32());
+      }
+      1 | 4 => {
+        assert_eq!(random32.next_u64(), block32.next_u64());
+        assert_eq!(random64.next_u64(), block64.next_u64());
+      }
+      _ => {
+        let size = i % 13;
+        let (mut x, mut y) = (vec![0u8; size], vec![0u8; size]);
+        random32.fill_bytes(&mut x);
+        block32.fill_bytes(&mut y);
+        assert_eq!(x, y);
+        random64.fill_bytes(&mut x);
+        block64.fill_bytes(&mut y);
+        assert_eq!(x, y);
+      }
+    }
+  }
+}
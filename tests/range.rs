@@ -0,0 +1,111 @@
+//! The golden values are frozen; a change of them breaks the stability guarantee of the bounded
+//! integer generation. They're generated with the parameter set id 0 of check32.c and check64.c.
+extern crate tinymt;
+
+use tinymt::tinymt32::tinymt32_generate_uint32;
+use tinymt::tinymt64::tinymt64_generate_uint64;
+use tinymt::{TinyMT32, TinyMT64};
+
+#[test]
+fn test_tinymt32_golden_values() {
+  let mut random = TinyMT32::builder().seed(1).build().unwrap();
+  assert_eq!([3, 1, 5, 3, 5, 5, 2, 3], [(); 8].map(|_| random.gen_below(6)));
+  assert_eq!([17, 11, 11, 14, 12, 20, 19, 18], [(); 8].map(|_| random.gen_range_u32(10..=20)));
+  assert_eq!(
+    [
+      680202414032,
+      952834766361,
+      509506869986,
+      427132318001,
+      703146380360,
+      288655400029,
+      794447769441,
+      37570588775
+    ],
+    [(); 8].map(|_| random.gen_range_u64(0..=1_000_000_000_000))
+  );
+  assert_eq!([-5, 1, 5, -4, 0, -5, -4, -1], [(); 8].map(|_| random.gen_range_i64(-5..=5)));
+  // a bound just above 2^31 rejects almost a half of the outputs
+  assert_eq!(
+    [260911158, 1146262227, 1959880961, 454610968],
+    [(); 4].map(|_| random.gen_below(0x8000_0001))
+  );
+}
+
+#[test]
+fn test_tinymt64_golden_values() {
+  let mut random = TinyMT64::builder().seed(1).build().unwrap();
+  assert_eq!([5, 5, 0, 3, 2, 4, 1, 3], [(); 8].map(|_| random.gen_below(6)));
+  assert_eq!([20, 11, 11, 12, 17, 16, 10, 13], [(); 8].map(|_| random.gen_range_u32(10..=20)));
+  assert_eq!(
+    [
+      404413193659,
+      53206070245,
+      642546912592,
+      323978895598,
+      903275484627,
+      270895208438,
+      604013613955,
+      672483805635
+    ],
+    [(); 8].map(|_| random.gen_range_u64(0..=1_000_000_000_000))
+  );
+  assert_eq!([1, 3, 4, 3, 1, 0, 3, 2], [(); 8].map(|_| random.gen_range_i64(-5..=5)));
+  assert_eq!(
+    [5992010593347383130, 1519975164684050250, 6783121655215158440, 4396854544372740090],
+    [(); 4].map(|_| random.gen_below(0x8000_0000_0000_0001))
+  );
+}
+
+#[test]
+fn test_full_and_single_ranges() {
+  // the whole range takes an output as it is
+  let mut random = TinyMT32::from_seed_u32(1);
+  let mut expected = random;
+  assert_eq!(tinymt32_generate_uint32(&mut expected), random.gen_range_u32(0..=u32::MAX));
+  let mut random = TinyMT64::from_seed_u64(1);
+  let mut expected = random;
+  assert_eq!(tinymt64_generate_uint64(&mut expected), random.gen_range_u64(0..=u64::MAX));
+  assert_eq!(
+    tinymt64_generate_uint64(&mut expected) as i64 ^ i64::MIN,
+    random.gen_range_i64(i64::MIN..=i64::MAX)
+  );
+
+  for _ in 0..100 {
+    assert_eq!(0, random.gen_below(1));
+    assert_eq!(7, random.gen_range_u32(7..=7));
+    assert_eq!(u64::MAX, random.gen_range_u64(u64::MAX..=u64::MAX));
+    assert_eq!(i64::MIN, random.gen_range_i64(i64::MIN..=i64::MIN));
+  }
+}
+
+#[test]
+fn test_uniformity() {
+  let mut random32 = TinyMT32::from_seed_u32(1234567890);
+  let mut random64 = TinyMT64::from_seed_u64(12345678901234);
+  let mut histogram = [[0usize; 7]; 4];
+  for _ in 0..70000 {
+    histogram[0][random32.gen_below(7) as usize] += 1;
+    histogram[1][(random32.gen_range_i64(-3..=3) + 3) as usize] += 1;
+    histogram[2][random64.gen_below(7) as usize] += 1;
+    histogram[3][(random64.gen_range_i64(-3..=3) + 3) as usize] += 1;
+  }
+  for counts in histogram {
+    for count in counts {
+      assert!((9500..10500).contains(&count), "{:?}", counts);
+    }
+  }
+}
+
+#[test]
+#[should_panic]
+fn test_gen_below_zero() {
+  TinyMT64::from_seed_u64(1).gen_below(0);
+}
+
+#[test]
+#[should_panic]
+fn test_empty_range() {
+  #[allow(clippy::reversed_empty_ranges)]
+  TinyMT32::from_seed_u32(1).gen_range_i64(1..=0);
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "std")]
+
+use std::io::Read;
+
+use tinymt::{Endian, RandomReader, TinyMT32, TinyMT64};
+
+/// Read `len` bytes from `reader` in reads of the sizes in `sizes`, repeated.
+fn read_in_pieces<R: Read>(reader: &mut R, len: usize, sizes: &[usize]) -> Vec<u8> {
+  let mut bytes = vec![0u8; len];
+  let mut position = 0;
+  for size in sizes.iter().cycle() {
+    if position == len {
+      break;
+    }
+    let end = (position + size).min(len);
+    assert_eq!(end - position, reader.read(&mut bytes[position..end]).unwrap());
+    position = end;
+  }
+  bytes
+}
+
+#[test]
+fn test_reader_tinymt64() {
+  let mut expected = vec![0u8; 1001];
+  TinyMT64::from_seed_u64(1).fill_bytes(&mut expected);
+  for sizes in [&[1001][..], &[1], &[3, 5], &[7, 0, 9, 16, 2]] {
+    let mut reader = RandomReader::new(TinyMT64::from_seed_u64(1));
+    assert_eq!(expected, read_in_pieces(&mut reader, 1001, sizes));
+  }
+
+  let mut random = TinyMT64::from_seed_u64(1);
+  let mut reader = RandomReader::with_endian(random, Endian::Big);
+  let bytes = read_in_pieces(&mut reader, 800, &[3, 13]);
+  assert!(bytes.chunks(8).all(|chunk| chunk == random.next_u64().to_be_bytes()));
+  assert_eq!(&random, reader.get_ref());
+}
+
+#[test]
+fn test_reader_tinymt32() {
+  let mut expected = vec![0u8; 1001];
+  TinyMT32::from_seed_u32(1).fill_bytes(&mut expected);
+  for sizes in [&[1001][..], &[1], &[3, 5], &[7, 0, 9, 16, 2]] {
+    let mut reader = RandomReader::new(TinyMT32::from_seed_u32(1));
+    assert_eq!(expected, read_in_pieces(&mut reader, 1001, sizes));
+  }
+
+  let mut random = TinyMT32::from_seed_u32(1);
+  let mut reader = RandomReader::with_endian(random, Endian::Big);
+  assert_eq!(Endian::Big, reader.endian());
+  let bytes = read_in_pieces(&mut reader, 400, &[3, 13]);
+  assert!(bytes.chunks(4).all(|chunk| chunk == random.next_u32().to_be_bytes()));
+  assert_eq!(random, reader.into_inner());
+}
+
+#[test]
+fn test_reader_take() {
+  let mut expected = vec![0u8; 137];
+  TinyMT64::from_seed_u64(1).fill_bytes(&mut expected);
+
+  // take() doesn't lose the bytes of the output it stops in the middle of
+  let mut reader = RandomReader::new(TinyMT64::from_seed_u64(1));
+  let mut bytes = Vec::new();
+  reader.by_ref().take(13).read_to_end(&mut bytes).unwrap();
+  reader.by_ref().take(87).read_to_end(&mut bytes).unwrap();
+  assert_eq!(expected[..100], bytes);
+
+  let mut bytes = [0u8; 37];
+  reader.read_exact(&mut bytes).unwrap();
+  assert_eq!(expected[100..], bytes);
+}
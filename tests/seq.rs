@@ -0,0 +1,92 @@
+//! The golden values are frozen; a change of them breaks the stability guarantee of the sequence
+//! utilities. They're generated with the parameter set id 0 of check32.c and check64.c.
+extern crate tinymt;
+
+use tinymt::{TinyMT32, TinyMT64};
+
+#[test]
+fn test_tinymt32_golden_values() {
+  let mut random = TinyMT32::builder().seed(1).build().unwrap();
+  let mut slice = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+  random.shuffle(&mut slice);
+  assert_eq!([0, 8, 7, 1, 4, 9, 3, 6, 2, 5], slice);
+  let items = [10, 20, 30, 40, 50, 60, 70];
+  assert_eq!([20, 20, 30, 20, 70], [(); 5].map(|_| *random.choose(&items).unwrap()));
+  assert_eq!([5, 16, 18, 28, 83], random.sample_indices(100, 5).collect::<Vec<_>>()[..]);
+  let mut reservoir = [0; 5];
+  assert_eq!(5, random.reservoir_sample(0..1000, &mut reservoir));
+  assert_eq!([470, 803, 772, 649, 187], reservoir);
+}
+
+#[test]
+fn test_tinymt64_golden_values() {
+  let mut random = TinyMT64::builder().seed(1).build().unwrap();
+  let mut slice = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+  random.shuffle(&mut slice);
+  assert_eq!([7, 5, 4, 1, 6, 2, 3, 0, 9, 8], slice);
+  let items = [10, 20, 30, 40, 50, 60, 70];
+  assert_eq!([10, 10, 20, 50, 50], [(); 5].map(|_| *random.choose(&items).unwrap()));
+  assert_eq!([42, 48, 56, 67, 79], random.sample_indices(100, 5).collect::<Vec<_>>()[..]);
+  let mut reservoir = [0; 5];
+  assert_eq!(5, random.reservoir_sample(0..1000, &mut reservoir));
+  assert_eq!([761, 559, 390, 882, 549], reservoir);
+}
+
+#[test]
+fn test_edge_cases() {
+  let mut random = TinyMT64::from_seed_u64(1);
+  let mut empty: [u8; 0] = [];
+  random.shuffle(&mut empty);
+  assert_eq!(None, random.choose(&empty));
+  assert_eq!(0, random.sample_indices(10, 0).count());
+  assert_eq!(0, random.reservoir_sample(0..0, &mut [0; 3]));
+
+  // all candidates are selected when k = n
+  assert!(random.sample_indices(10, 10).eq(0..10));
+  let mut indices = random.sample_indices(10, 3);
+  assert_eq!(3, indices.len());
+  indices.next();
+  assert_eq!(2, indices.len());
+
+  // fewer items than the reservoir
+  let mut reservoir = [0; 5];
+  assert_eq!(3, random.reservoir_sample([7, 8, 9], &mut reservoir));
+  assert_eq!([7, 8, 9, 0, 0], reservoir);
+}
+
+#[test]
+#[should_panic]
+fn test_sample_too_many_indices() {
+  TinyMT32::from_seed_u32(1).sample_indices(3, 4);
+}
+
+#[test]
+fn test_uniformity() {
+  let mut random32 = TinyMT32::from_seed_u32(1234567890);
+  let mut random64 = TinyMT64::from_seed_u64(12345678901234);
+  // the position of each element after shuffle, and the frequency of each sampled index
+  let mut positions = [[0usize; 5]; 2];
+  let mut samples = [[0usize; 5]; 2];
+  let mut reservoirs = [[0usize; 5]; 2];
+  for _ in 0..50000 {
+    let mut slice = [0, 1, 2, 3, 4];
+    random32.shuffle(&mut slice);
+    positions[0][slice.iter().position(|i| *i == 0).unwrap()] += 1;
+    let mut slice = [0, 1, 2, 3, 4];
+    random64.shuffle(&mut slice);
+    positions[1][slice.iter().position(|i| *i == 0).unwrap()] += 1;
+    random32.sample_indices(5, 2).for_each(|i| samples[0][i] += 1);
+    random64.sample_indices(5, 2).for_each(|i| samples[1][i] += 1);
+    let mut reservoir = [0; 2];
+    random32.reservoir_sample(0..5, &mut reservoir);
+    reservoir.iter().for_each(|i| reservoirs[0][*i] += 1);
+    random64.reservoir_sample(0..5, &mut reservoir);
+    reservoir.iter().for_each(|i| reservoirs[1][*i] += 1);
+  }
+  for counts in positions {
+    assert!(counts.iter().all(|count| (9500..10500).contains(count)), "{:?}", counts);
+  }
+  for counts in samples.iter().chain(reservoirs.iter()) {
+    assert!(counts.iter().all(|count| (19000..21000).contains(count)), "{:?}", counts);
+  }
+}
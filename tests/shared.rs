@@ -0,0 +1,38 @@
+#![cfg(feature = "critical-section")]
+extern crate tinymt;
+
+use tinymt::{SharedTinyMT32, TinyMT32};
+
+#[test]
+fn test_same_stream_as_tinymt32() {
+  let mut random = TinyMT32::from_seed_u32(1234567890);
+  let shared = SharedTinyMT32::new(TinyMT32::from_seed_u32(1234567890));
+  for size in 0..20 {
+    assert_eq!(random.next_u32(), shared.next_u32());
+    assert_eq!(random.next_u64(), shared.next_u64());
+    let (mut x, mut y) = (vec![0u8; size], vec![0u8; size]);
+    random.fill_bytes(&mut x);
+    shared.fill_bytes(&mut y);
+    assert_eq!(x, y);
+  }
+  assert_eq!(random, shared.replace(TinyMT32::from_seed_u32(1)));
+  assert_eq!(TinyMT32::from_seed_u32(1).next_u32(), shared.next_u32());
+}
+
+/// Each output of the stream is taken exactly once by the threads sharing a generator.
+#[test]
+#[cfg(not(target_family = "wasm"))]
+fn test_shared_between_threads() {
+  static SHARED: SharedTinyMT32 = SharedTinyMT32::new(TinyMT32::from_seed_u32(1));
+  let mut outputs = (0..4)
+    .map(|_| std::thread::spawn(|| (0..10000).map(|_| SHARED.next_u32()).collect::<Vec<_>>()))
+    .collect::<Vec<_>>()
+    .into_iter()
+    .flat_map(|handle| handle.join().unwrap())
+    .collect::<Vec<_>>();
+  let mut random = TinyMT32::from_seed_u32(1);
+  let mut expected = (0..40000).map(|_| random.next_u32()).collect::<Vec<_>>();
+  outputs.sort_unstable();
+  expected.sort_unstable();
+  assert_eq!(expected, outputs);
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "sync")]
+extern crate tinymt;
+
+use tinymt::sync::AtomicTinyMT64;
+#[cfg(feature = "alloc")]
+use tinymt::sync::ShardedTinyMT64;
+#[cfg(feature = "alloc")]
+use tinymt::tinymt64::tinymt64_jump;
+use tinymt::TinyMT64;
+
+#[test]
+fn test_same_stream_as_tinymt64() {
+  let mut random = TinyMT64::from_seed_u64(12345678901234);
+  let atomic = AtomicTinyMT64::new(TinyMT64::from_seed_u64(12345678901234));
+  for size in 0..20 {
+    assert_eq!(random.next_u64(), atomic.next_u64());
+    assert_eq!(random.next_u32(), atomic.next_u32());
+    let (mut x, mut y) = (vec![0u8; size], vec![0u8; size]);
+    random.fill_bytes(&mut x);
+    atomic.fill_bytes(&mut y);
+    assert_eq!(x, y);
+  }
+  assert_eq!(random, atomic.load());
+}
+
+/// Each output of the stream is taken exactly once by the threads sharing a generator.
+#[test]
+#[cfg(not(target_family = "wasm"))]
+fn test_shared_between_threads() {
+  static ATOMIC: AtomicTinyMT64 = AtomicTinyMT64::new(TinyMT64::from_seed_u64(1));
+  let mut outputs = (0..8)
+    .map(|_| std::thread::spawn(|| (0..10000).map(|_| ATOMIC.next_u64()).collect::<Vec<_>>()))
+    .collect::<Vec<_>>()
+    .into_iter()
+    .flat_map(|handle| handle.join().unwrap())
+    .collect::<Vec<_>>();
+  let mut random = TinyMT64::from_seed_u64(1);
+  let mut expected = (0..80000).map(|_| random.next_u64()).collect::<Vec<_>>();
+  outputs.sort_unstable();
+  expected.sort_unstable();
+  assert_eq!(expected, outputs);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_shards_are_jumped_substreams() {
+  let random = TinyMT64::builder().seed(1).build().unwrap();
+  let sharded = ShardedTinyMT64::new(random, 3);
+  assert_eq!(3, sharded.shard_count());
+  for i in 0..3 {
+    let mut expected = random;
+    tinymt64_jump(&mut expected, i as u128 * ShardedTinyMT64::SHARD_STRIDE);
+    assert_eq!(expected, sharded.shard(i).load());
+    assert_eq!(expected.next_u64(), sharded.shard(i).next_u64());
+  }
+}
+
+#[test]
+#[cfg(all(feature = "std", not(target_family = "wasm")))]
+fn test_threads_use_local_shards() {
+  let sharded = std::sync::Arc::new(ShardedTinyMT64::new(TinyMT64::from_seed_u64(1), 4));
+  let handles = (0..4)
+    .map(|_| {
+      let sharded = sharded.clone();
+      std::thread::spawn(move || {
+        let shard = sharded.local_shard() as *const _ as usize;
+        let values = (0..100).map(|_| sharded.next_u64()).collect::<Vec<_>>();
+        (shard, values)
+      })
+    })
+    .collect::<Vec<_>>();
+  for handle in handles {
+    let (shard, values) = handle.join().unwrap();
+    assert!((0..4).any(|i| sharded.shard(i) as *const _ as usize == shard));
+    assert_eq!(100, values.len());
+  }
+}
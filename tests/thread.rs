@@ -0,0 +1,82 @@
+#![cfg(feature = "std")]
+extern crate tinymt;
+
+use tinymt::{thread_rng32, thread_rng64, TinyMT32, TinyMT64};
+
+#[test]
+fn test_handles_share_thread_generator() {
+  let mut a = thread_rng64();
+  let mut b = a.clone();
+  let mut c = thread_rng64();
+  let values = [a.next_u64(), b.next_u64(), c.next_u64()];
+  assert!(values[0] != values[1] && values[1] != values[2]);
+
+  let mut a = thread_rng32();
+  let mut b = thread_rng32();
+  let (x, y) = (a.next_u32(), b.next_u32());
+  assert_ne!(x, y);
+  let mut bytes = [0u8; 13];
+  a.fill_bytes(&mut bytes);
+  assert_ne!([0u8; 13], bytes);
+}
+
+#[test]
+#[cfg(not(target_family = "wasm"))]
+fn test_threads_have_distinct_generators() {
+  let outputs = (0..4)
+    .map(|_| std::thread::spawn(|| [(); 4].map(|_| thread_rng64().next_u64())))
+    .collect::<Vec<_>>()
+    .into_iter()
+    .map(|handle| handle.join().unwrap())
+    .collect::<Vec<_>>();
+  for i in 0..outputs.len() {
+    for j in i + 1..outputs.len() {
+      assert_ne!(outputs[i], outputs[j]);
+    }
+  }
+}
+
+/// Run `print_thread_outputs` in a child process with `TINYMT_SEED`, since the variable is read
+/// once in a process.
+#[cfg(not(target_family = "wasm"))]
+fn thread_outputs_with_seed(seed: &str) -> String {
+  let output = std::process::Command::new(std::env::current_exe().unwrap())
+    .args(["print_thread_outputs", "--ignored", "--nocapture", "--test-threads=1"])
+    .env("TINYMT_SEED", seed)
+    .output()
+    .unwrap();
+  assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+  let stdout = String::from_utf8(output.stdout).unwrap();
+  stdout.lines().find_map(|line| line.split_once("OUTPUTS ")).unwrap().1.to_string()
+}
+
+/// The first outputs of the thread generators of the threads named "alpha" and "b", which start
+/// at the same time, and of an unnamed thread after them.
+#[test]
+#[ignore = "run by test_tinymt_seed in a child process"]
+fn print_thread_outputs() {
+  let outputs = || (thread_rng64().next_u64(), thread_rng32().next_u32());
+  let named = ["alpha", "b"]
+    .map(|name| std::thread::Builder::new().name(name.to_string()).spawn(outputs).unwrap())
+    .map(|handle| handle.join().unwrap());
+  let unnamed = std::thread::spawn(outputs).join().unwrap();
+  println!("OUTPUTS {:?} {:?}", named, unnamed);
+}
+
+#[test]
+#[cfg(not(target_family = "wasm"))]
+fn test_tinymt_seed() {
+  let outputs = |key64: &[u64], key32: &[u32]| {
+    let mut random64 = TinyMT64::builder().seed_array(key64).build().unwrap();
+    let mut random32 = TinyMT32::builder().seed_array(key32).build().unwrap();
+    (random64.next_u64(), random32.next_u32())
+  };
+  // the seed, the length of the name and its bytes in little-endian words
+  let alpha = outputs(&[42, 5, 0x61_68_70_6c_61], &[42, 0, 5, 0, 0x6870_6c61, 0x61]);
+  let b = outputs(&[42, 1, 0x62], &[42, 0, 1, 0, 0x62, 0]);
+  // the seed and the number of the generators created before
+  let unnamed = outputs(&[42, 2], &[42, 0, 2, 0]);
+  let expected = format!("{:?} {:?}", [alpha, b], unnamed);
+  assert_eq!(expected, thread_outputs_with_seed("42"));
+  assert_eq!(expected, thread_outputs_with_seed("0x2a"));
+}
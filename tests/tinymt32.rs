@@ -22,6 +22,19 @@ fn test_cases_by_author() {
   test_generate_32double(&mut tinymt);
 }
 
+/// The same sequence as check32.c computed at compile time.
+#[test]
+fn test_const_array() {
+  const ARRAY: [u32; 5] = tinymt32_const_array(0x8f7011ee, 0xfc78ff1f, 0x3793fdff, 1);
+  static RANDOM: TinyMT32 = {
+    let mut random = TinyMT32::new([0, 0, 0, 0], 0x8f7011ee, 0xfc78ff1f, 0x3793fdff);
+    tinymt32_init(&mut random, 1);
+    random
+  };
+  assert_eq!([2545341989, 981918433, 3715302833, 2387538352, 3591001365], ARRAY);
+  assert_eq!(ARRAY[0], tinymt32_generate_uint32(&mut RANDOM.clone()));
+}
+
 /// 32-bit unsigned integers r, where 0 <= r < 2^32
 fn test_generate_uint32(tinymt: &mut TinyMT32) {
   let expected = [
@@ -152,3 +165,136 @@ fn test_generate_32double(tinymt: &mut TinyMT32) {
     }
   }
 }
+
+/// The first outputs of each float function just after `tinymt32_init()` with the parameters and
+/// seed of check32.c, as exact bit patterns produced by the original C implementation.
+#[test]
+fn test_golden_vectors() {
+  #[allow(clippy::type_complexity)]
+  let cases: [(fn(&mut TinyMT32) -> f32, fn(f32) -> bool, [u32; 6]); 5] = [
+    (
+      tinymt32_generate_float,
+      |r| (0.0..1.0).contains(&r),
+      [0x3f17b6d6, 0x3e6a1b88, 0x3f5d7305, 0x3f0e4ef1, 0x3f560a55, 0x3f63b751],
+    ),
+    (
+      tinymt32_generate_float12,
+      |r| (1.0..2.0).contains(&r),
+      [0x3fcbdb6b, 0x3f9d4371, 0x3feeb982, 0x3fc72778, 0x3feb052a, 0x3ff1dba8],
+    ),
+    (
+      tinymt32_generate_float01,
+      |r| (0.0..1.0).contains(&r),
+      [0x3f17b6d6, 0x3e6a1b88, 0x3f5d7304, 0x3f0e4ef0, 0x3f560a54, 0x3f63b750],
+    ),
+    (
+      // two steps per output, as in the original implementation
+      tinymt32_generate_float_oc,
+      |r| 0.0 < r && r <= 1.0,
+      [0x3f45791e, 0x3ee3621e, 0x3de24578, 0x3efa3442, 0x3f526e22, 0x3f136023],
+    ),
+    (
+      tinymt32_generate_float_oo,
+      |r| 0.0 < r && r < 1.0,
+      [0x3f17b6d6, 0x3e6a1b88, 0x3f5d7306, 0x3f0e4ef2, 0x3f560a56, 0x3f63b752],
+    ),
+  ];
+  for (generate, in_interval, expected) in cases {
+    let mut tinymt = TinyMT32::new([0, 0, 0, 0], 0x8f7011ee, 0xfc78ff1f, 0x3793fdff);
+    tinymt32_init(&mut tinymt, 1);
+    for bits in expected {
+      let actual = generate(&mut tinymt);
+      assert_eq!(bits, actual.to_bits());
+      assert!(in_interval(actual));
+    }
+  }
+
+  let mut tinymt = TinyMT32::new([0, 0, 0, 0], 0x8f7011ee, 0xfc78ff1f, 0x3793fdff);
+  tinymt32_init(&mut tinymt, 1);
+  for bits in [
+    0x3fe2f6dac4a00000,
+    0x3fcd437170800000,
+    0x3febae60b6200000,
+    0x3fe1c9de36000000,
+    0x3feac14aa2a00000,
+    0x3fec76ea3ec00000,
+  ] {
+    let actual = tinymt32_generate_32double(&mut tinymt);
+    assert_eq!(bits, actual.to_bits());
+    assert!((0.0..1.0).contains(&actual));
+  }
+}
+
+/// 53-bit double numbers made from two 32-bit outputs as genrand_res53() of MT19937.
+#[test]
+fn test_generate_53double() {
+  let mut tinymt = TinyMT32::new([0, 0, 0, 0], 0x8f7011ee, 0xfc78ff1f, 0x3793fdff);
+  tinymt32_init(&mut tinymt, 1);
+  let mut uint32 = tinymt;
+  let mut genrand_res53 = || {
+    let a = (tinymt32_generate_uint32(&mut uint32) >> 5) as f64;
+    let b = (tinymt32_generate_uint32(&mut uint32) >> 6) as f64;
+    (a * 67108864.0 + b) * (1.0 / 9007199254740992.0)
+  };
+  let (mut co, mut oc, mut oo, mut high_level) = (tinymt, tinymt, tinymt, tinymt);
+  for _ in 0..10000 {
+    let expected = genrand_res53();
+    let actual = tinymt32_generate_53double(&mut co);
+    assert_eq!(expected.to_bits(), actual.to_bits());
+    assert!((0.0..1.0).contains(&actual));
+    assert_eq!(actual.to_bits(), high_level.next_f64().to_bits());
+
+    let actual = tinymt32_generate_53double_oc(&mut oc);
+    assert_eq!(expected + 1.0 / 9007199254740992.0, actual);
+    assert!(0.0 < actual && actual <= 1.0);
+
+    let actual = tinymt32_generate_53double_oo(&mut oo);
+    assert!((expected - actual).abs() <= 1.0 / 9007199254740992.0);
+    assert!(0.0 < actual && actual < 1.0);
+  }
+  assert_eq!(uint32, co);
+  assert_eq!(uint32, oc);
+  assert_eq!(uint32, oo);
+}
+
+/// Full precision float numbers are finer than a fixed step below 1/2.
+#[test]
+fn test_generate_full() {
+  let mut tinymt = TinyMT32::from_seed_u32(1234567890);
+  let (mut float_fine, mut double_fine, mut float_half, mut double_half) = (0, 0, 0, 0);
+  for _ in 0..10000 {
+    let mut expected = tinymt;
+    let r = tinymt32_generate_float_full(&mut tinymt);
+    assert!((0.0..1.0).contains(&r));
+    if r < 0.5 {
+      float_half += 1;
+    }
+    // not a multiple of 2^-24 as tinymt32_generate_float()
+    if (r * 16777216.0).fract() != 0.0 {
+      float_fine += 1;
+    }
+    tinymt32_generate_uint32(&mut expected);
+    tinymt32_generate_uint32(&mut expected);
+    if r >= 2.0f32.powi(-32) {
+      assert_eq!(expected, tinymt);
+    }
+
+    let r = tinymt.next_f64_full();
+    assert!((0.0..1.0).contains(&r));
+    if r < 0.5 {
+      double_half += 1;
+    }
+    // not a multiple of 2^-53 as tinymt32_generate_53double()
+    if (r * 9007199254740992.0).fract() != 0.0 {
+      double_fine += 1;
+    }
+  }
+  // a half of the numbers are in [0, 1/2); 1/2 of those in [1/4, 1/2), 3/4 of those in
+  // [1/8, 1/4) and so on are finer than the fixed step, 1/3 of all numbers in total
+  for count in [float_half, double_half] {
+    assert!((4500..5500).contains(&count), "{}", count);
+  }
+  for count in [float_fine, double_fine] {
+    assert!((3000..3700).contains(&count), "{}", count);
+  }
+}
@@ -25,6 +25,19 @@ fn test_cases_by_author() {
   test_generate_double_oo(&mut tinymt);
 }
 
+/// The same sequence as check64.c computed at compile time.
+#[test]
+fn test_const_array() {
+  const ARRAY: [u64; 3] = tinymt64_const_array(0xfa051f40, 0xffd0fff4, 0x58d02ffeffbfffbc, 1);
+  static RANDOM: TinyMT64 = {
+    let mut random = TinyMT64::new([0, 0], 0xfa051f40, 0xffd0fff4, 0x58d02ffeffbfffbc);
+    tinymt64_init(&mut random, 1);
+    random
+  };
+  assert_eq!([15503804787016557143, 17280942441431881838, 2177846447079362065], ARRAY);
+  assert_eq!(ARRAY[0], tinymt64_generate_uint64(&mut RANDOM.clone()));
+}
+
 /// 64-bit unsigned integers r, where 0 <= r < 2^64
 fn test_generate_uint64(tinymt: &mut TinyMT64) {
   let expected = [
@@ -140,3 +153,177 @@ fn test_generate_double_oo(tinymt: &mut TinyMT64) {
     }
   }
 }
+
+/// The first outputs of each double function just after `tinymt64_init()` with the parameters
+/// and seed of check64.c, as exact bit patterns produced by the original C implementation.
+#[test]
+fn test_golden_vectors() {
+  #[allow(clippy::type_complexity)]
+  let cases: [(fn(&mut TinyMT64) -> f64, fn(f64) -> bool, [u64; 6]); 5] = [
+    (
+      tinymt64_generate_double,
+      |r| (0.0..1.0).contains(&r),
+      [
+        0x3feae51289f4a3ec,
+        0x3fedfa47b2f2b09b,
+        0x3fbe3943ce0d8308,
+        0x3fe17ff680353459,
+        0x3fdef71d839757ae,
+        0x3fe69a96610a1833,
+      ],
+    ),
+    (
+      tinymt64_generate_double12,
+      |r| (1.0..2.0).contains(&r),
+      [
+        0x3ffd728944fa51f6,
+        0x3ffefd23d979584d,
+        0x3ff1e3943ce0d830,
+        0x3ff8bffb401a9a2c,
+        0x3ff7bdc760e5d5eb,
+        0x3ffb4d4b30850c19,
+      ],
+    ),
+    (
+      tinymt64_generate_double01,
+      |r| (0.0..1.0).contains(&r),
+      [
+        0x3feae51289f4a3ec,
+        0x3fedfa47b2f2b09a,
+        0x3fbe3943ce0d8300,
+        0x3fe17ff680353458,
+        0x3fdef71d839757ac,
+        0x3fe69a96610a1832,
+      ],
+    ),
+    (
+      tinymt64_generate_double_oc,
+      |r| 0.0 < r && r <= 1.0,
+      [
+        0x3fc46bb5d82d7050,
+        0x3fb02dc2686a7b30,
+        0x3fec38d7863e4fa0,
+        0x3fdd0012ff959750,
+        0x3fe084713e34542a,
+        0x3fd2cad33debcf9c,
+      ],
+    ),
+    (
+      tinymt64_generate_double_oo,
+      |r| 0.0 < r && r < 1.0,
+      [
+        0x3feae51289f4a3ee,
+        0x3fedfa47b2f2b09a,
+        0x3fbe3943ce0d8310,
+        0x3fe17ff68035345a,
+        0x3fdef71d839757ac,
+        0x3fe69a96610a1832,
+      ],
+    ),
+  ];
+  for (generate, in_interval, expected) in cases {
+    let mut tinymt = TinyMT64::new([0, 0], 0xfa051f40, 0xffd0fff4, 0x58d02ffeffbfffbc);
+    tinymt64_init(&mut tinymt, 1);
+    for bits in expected {
+      let actual = generate(&mut tinymt);
+      assert_eq!(bits, actual.to_bits());
+      assert!(in_interval(actual));
+    }
+  }
+}
+
+/// Single precision float numbers made directly from the 64-bit outputs.
+#[test]
+fn test_generate_float() {
+  let mut tinymt = TinyMT64::new([0, 0], 0xfa051f40, 0xffd0fff4, 0x58d02ffeffbfffbc);
+  tinymt64_init(&mut tinymt, 1);
+  let mut uint64 = tinymt;
+  let (mut co, mut oc, mut oo, mut f12, mut pair, mut high_level) =
+    (tinymt, tinymt, tinymt, tinymt, tinymt, tinymt);
+  for _ in 0..10000 {
+    let x = tinymt64_generate_uint64(&mut uint64);
+    let expected = (x >> 40) as f32 / 16777216.0;
+
+    let actual = tinymt64_generate_float(&mut co);
+    assert_eq!(expected, actual);
+    assert!((0.0..1.0).contains(&actual));
+    assert_eq!(actual.to_bits(), high_level.next_f32().to_bits());
+
+    let actual = tinymt64_generate_float_oc(&mut oc);
+    assert_eq!(expected + 1.0 / 16777216.0, actual);
+    assert!(0.0 < actual && actual <= 1.0);
+
+    let actual = tinymt64_generate_float_oo(&mut oo);
+    assert!((expected - actual).abs() <= 1.0 / 16777216.0);
+    assert!(0.0 < actual && actual < 1.0);
+
+    let actual = tinymt64_generate_float12(&mut f12);
+    assert_eq!(((x >> 41) as f32 / 8388608.0) + 1.0, actual);
+    assert!((1.0..2.0).contains(&actual));
+
+    let [first, second] = tinymt64_generate_float_pair(&mut pair);
+    assert_eq!(expected, first);
+    assert_eq!(((x as u32) >> 8) as f32 / 16777216.0, second);
+    assert!((0.0..1.0).contains(&second));
+  }
+  for actual in [co, oc, oo, f12, pair] {
+    assert_eq!(uint64, actual);
+  }
+
+  // the cast of a double close to 1.0 rounds up to 1.0, which tinymt64_generate_float() avoids
+  assert_eq!(1.0, (1.0 - 1.0 / 9007199254740992.0) as f32);
+  assert_eq!(1.0 - 1.0 / 16777216.0, ((u64::MAX >> 40) as f32) / 16777216.0);
+}
+
+/// Full precision float numbers are finer than a fixed step below 1/2.
+#[test]
+fn test_generate_full() {
+  let mut tinymt = TinyMT64::from_seed_u64(12345678901234);
+  let (mut float_fine, mut double_fine, mut float_half, mut double_half) = (0, 0, 0, 0);
+  for _ in 0..10000 {
+    let mut expected = tinymt;
+    let r = tinymt64_generate_double_full(&mut tinymt);
+    assert!((0.0..1.0).contains(&r));
+    if r < 0.5 {
+      double_half += 1;
+    }
+    // not a multiple of 2^-53 as tinymt64_generate_double()
+    if (r * 9007199254740992.0).fract() != 0.0 {
+      double_fine += 1;
+    }
+    tinymt64_generate_uint64(&mut expected);
+    tinymt64_generate_uint64(&mut expected);
+    if r >= 2.0f64.powi(-64) {
+      assert_eq!(expected, tinymt);
+    }
+
+    let r = tinymt.next_f32_full();
+    assert!((0.0..1.0).contains(&r));
+    if r < 0.5 {
+      float_half += 1;
+    }
+    // not a multiple of 2^-24 as tinymt64_generate_float()
+    if (r * 16777216.0).fract() != 0.0 {
+      float_fine += 1;
+    }
+  }
+  // a half of the numbers are in [0, 1/2); 1/2 of those in [1/4, 1/2), 3/4 of those in
+  // [1/8, 1/4) and so on are finer than the fixed step, 1/3 of all numbers in total
+  for count in [float_half, double_half] {
+    assert!((4500..5500).contains(&count), "{}", count);
+  }
+  for count in [float_fine, double_fine] {
+    assert!((3000..3700).contains(&count), "{}", count);
+  }
+}
+
+#[test]
+fn test_jump_states() {
+  for stride in [1, 2, 1000, 1 << 64] {
+    let mut expected = TinyMT64::from_seed_u64(1);
+    for state in tinymt64_jump_states(&expected, stride).take(4) {
+      assert_eq!(expected, state);
+      tinymt64_jump(&mut expected, stride);
+    }
+  }
+}